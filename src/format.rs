@@ -2,60 +2,140 @@
 
 #![allow(clippy::cast_precision_loss)]
 
+mod bidi;
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "cldr")]
+pub mod cldr;
+mod currency;
+mod digit;
+mod locale;
+mod marker;
+mod notation;
+mod overflow;
+#[cfg(feature = "alloc")]
+mod pin;
+mod placeholder;
+mod placement;
 mod preset;
+mod sign;
 
-#[cfg(feature = "alloc")]
-use alloc::format;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
-use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::num::NonZeroU128;
 
 use const_for::const_for;
 
-use crate::unit::RangedUnit;
+pub use self::bidi::Bidi;
+#[cfg(feature = "alloc")]
+pub use self::builder::{BuildError, FormatterBuilder, FormatterOptions, OwnedFormatter};
+pub use self::currency::Currency;
+pub use self::digit::DigitSet;
+pub use self::locale::Locale;
+pub use self::marker::Markers;
+pub use self::notation::{ExponentStyle, Notation};
+pub use self::overflow::{Overflow, OverflowError};
+#[cfg(feature = "alloc")]
+pub use self::pin::PinBy;
+pub use self::placeholder::Placeholders;
+pub use self::placement::{Placement, UnitPosition};
+pub use self::sign::SignStyle;
+use crate::unit::{Ladder, LadderError, Magnitude, Pinned, RangedUnit, Selection, UnitRef, UnitSystem};
 
 #[derive(Debug, Clone, Copy)]
 /// Formatter of numbers for human-readable output.
-pub struct Formatter<const N: usize = 0> {
+///
+/// The units, the separator and the strings of the other options, e.g.
+/// placeholders or the placement, are borrowed for `'a`, so they can be
+/// loaded at runtime, e.g. from a translation file.
+///
+/// The ranged units are a slice, so formatters with different units share
+/// the same type. Units computed rather than tabulated are plugged in with
+/// [`Formatter::with_unit_system`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::Formatter;
+///
+/// let formatters = [Formatter::SI, Formatter::BINARY, Formatter::CHINESE];
+///
+/// let formatted = formatters.map(|formatter| formatter.format(1_000_000).to_string());
+/// assert_eq!(formatted, ["1.00 M", "976.56 Ki", "100.00 万"]);
+/// ```
+pub struct Formatter<'a, S = Ladder<'a>> {
     /// Separator between numbers and units.
     ///
     /// Defaults to be " " (space)
-    separator: &'static str,
+    separator: &'a str,
 
     /// The abbreviated number's units.
     ///
     /// If the number is too large and no corresponding unit is found, the
-    /// scientific notation like `3.0e99` will be used.
-    ranged_units: &'static [RangedUnit; N],
+    /// [`Overflow`] policy applies.
+    unit_system: S,
 
     /// The custom unit attached after the abbreviated number's unit.
-    custom_unit: Option<&'static str>,
+    custom_unit: Option<&'a str>,
+
+    /// The approximation and saturation markers, disabled by default.
+    markers: Option<Markers<'a>>,
+
+    /// What to do with a number exceeding the last unit.
+    overflow: Overflow,
+
+    /// How numbers are rendered.
+    notation: Notation,
+
+    /// How the exponent of scientific or engineering notation is rendered.
+    exponent_style: ExponentStyle,
+
+    /// Placeholders for non-finite, negative zero and missing numbers.
+    placeholders: Placeholders<'a>,
+
+    /// How the sign is rendered.
+    sign_style: SignStyle<'a>,
+
+    /// The decimal mark and digit grouping.
+    locale: Locale<'a>,
+
+    /// The digits the number is transliterated into.
+    digit_set: DigitSet<'a>,
+
+    /// The bidi controls for right-to-left contexts.
+    bidi: Bidi,
+
+    /// The placement of prefix, suffix and units.
+    placement: Placement<'a>,
 }
 
-impl Formatter {
+impl Formatter<'static> {
     /// Binary units (`Ki`, `Mi`, `Gi`, `Ti`, `Pi`, `Ei`, `Zi`, `Yi`)
-    pub const BINARY: Formatter<9> = Formatter {
-        ranged_units: &preset::BINARY_UNITS,
-        separator: " ",
-        custom_unit: None,
-    };
-    /// Chinese units (`万`, `亿`, `兆`, `京`, `垓`, `秭`, `穰`, `沟`)
-    pub const CHINESE: Formatter<9> = Formatter {
-        ranged_units: &preset::CHINESE_UNITS,
-        separator: " ",
-        custom_unit: None,
-    };
-    /// Decimal units (`K`, `M`, `G`, `T`, `P`, `E`, `Z`, `Y`)
-    pub const SI: Formatter<9> = Formatter {
-        ranged_units: &preset::SI_UNITS,
-        separator: " ",
-        custom_unit: None,
-    };
+    pub const BINARY: Formatter<'static> = Self::preset(&preset::BINARY_UNITS, Locale::DEFAULT);
+    /// Chinese units (`万`, `亿`, `兆`, `京`, `垓`, `秭`, `穰`, `沟`, `涧`,
+    /// `正`, `载`, `极`)
+    pub const CHINESE: Formatter<'static> = Self::preset(&preset::CHINESE_UNITS, Locale::DEFAULT);
+    /// French units (`k`, `M`, `Md`, `Bn`), with [`Locale::FR`]
+    pub const FRENCH: Formatter<'static> = Self::preset(&preset::FRENCH_UNITS, Locale::FR);
+    /// German units (`Tsd`, `Mio`, `Mrd`, `Bio`), with [`Locale::DE`]
+    pub const GERMAN: Formatter<'static> = Self::preset(&preset::GERMAN_UNITS, Locale::DE);
+    /// Italian units (`mila`, `Mln`, `Mrd`, `Bln`), with [`Locale::IT`]
+    pub const ITALIAN: Formatter<'static> = Self::preset(&preset::ITALIAN_UNITS, Locale::IT);
+    /// Portuguese units (`mil`, `mi`, `bi`, `tri`), with [`Locale::PT`]
+    pub const PORTUGUESE: Formatter<'static> = Self::preset(&preset::PORTUGUESE_UNITS, Locale::PT);
+    /// Russian units (`тыс.`, `млн`, `млрд`, `трлн`), with [`Locale::RU`]
+    pub const RUSSIAN: Formatter<'static> = Self::preset(&preset::RUSSIAN_UNITS, Locale::RU);
+    /// Decimal units (`K`, `M`, `G`, `T`, `P`, `E`, `Z`, `Y`, `R`, `Q`)
+    pub const SI: Formatter<'static> = Self::preset(&preset::SI_UNITS, Locale::DEFAULT);
+    /// Spanish units (`mil`, `M`, `mil M`, `B`), with [`Locale::ES`]
+    pub const SPANISH: Formatter<'static> = Self::preset(&preset::SPANISH_UNITS, Locale::ES);
 }
 
-impl<const N: usize> Formatter<N> {
+impl<'a> Formatter<'a> {
     #[inline]
-    #[must_use]
     /// Creates a custom formatter with the given ranged units.
     ///
     /// ## Constrains
@@ -63,23 +143,40 @@ impl<const N: usize> Formatter<N> {
     /// - The first `ranged_unit.range_max` is the base, the nth
     ///   `ranged_unit.range_max` is the nth power of the first `ranged_unit.
     ///   range_max`.
+    /// - The base SHOULD NOT exceed `u128`, and the `range_max` exceeding
+    ///   `u128` SHOULD be described with the same base, like
+    ///   `Magnitude::new(base, n)`.
     /// - `ranged_units` SHOULD NOT be empty.
-    pub const fn custom(ranged_units: &'static [RangedUnit; N]) -> Option<Self> {
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`LadderError`] describing the first violated constraint.
+    pub const fn custom(ranged_units: &'a [RangedUnit<'a>]) -> Result<Self, LadderError> {
         if ranged_units.is_empty() {
-            return None;
+            return Err(LadderError::Empty);
         }
 
-        let base = ranged_units[0].range_max.get();
+        let Some(base) = ranged_units[0].range_max.get() else {
+            return Err(LadderError::BaseOverflow);
+        };
+
+        let Some(base) = NonZeroU128::new(base) else {
+            return Err(LadderError::ZeroBase);
+        };
+
+        const_for!(index in 1..ranged_units.len() => {
+            if !ranged_units[index - 1].range_max.less_than(&ranged_units[index].range_max) {
+                return Err(LadderError::NonMonotonic { index });
+            }
 
-        const_for!(i in 1..N => {
             #[allow(clippy::cast_possible_truncation)]
-            if ranged_units[i].range_max.get() != base.pow((i + 1) as u32) {
-                return None;
+            if !ranged_units[index].range_max.equals(&Magnitude::new(base, (index + 1) as u32)) {
+                return Err(LadderError::NotPowerOfBase { index });
             }
         });
 
         #[allow(unsafe_code, reason = "Has checked")]
-        Some(unsafe { Self::custom_unchecked(ranged_units) })
+        Ok(unsafe { Self::custom_unchecked(ranged_units) })
     }
 
     #[allow(
@@ -93,37 +190,466 @@ impl<const N: usize> Formatter<N> {
     /// ## Safety
     ///
     /// See [`Formatter::custom`].
-    pub const unsafe fn custom_unchecked(ranged_units: &'static [RangedUnit; N]) -> Self {
+    pub const unsafe fn custom_unchecked(ranged_units: &'a [RangedUnit<'a>]) -> Self {
+        Self::preset(ranged_units, Locale::DEFAULT)
+    }
+
+    #[inline]
+    /// Creates a formatter with the given ranged units and locale, and the
+    /// default options otherwise.
+    const fn preset(ranged_units: &'a [RangedUnit<'a>], locale: Locale<'a>) -> Self {
         Self {
             separator: " ",
-            ranged_units,
+            unit_system: Ladder::new(ranged_units),
             custom_unit: None,
+            markers: None,
+            overflow: Overflow::Unscaled,
+            notation: Notation::Units,
+            exponent_style: ExponentStyle::Lowercase,
+            placeholders: Placeholders::DEFAULT,
+            sign_style: SignStyle::HYPHEN,
+            locale,
+            digit_set: DigitSet::ASCII,
+            bidi: Bidi::Off,
+            placement: Placement::DEFAULT,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Replaces the ranged units with a [`UnitSystem`], keeping the other
+    /// options.
+    ///
+    /// The unit system is not checked like [`Formatter::custom`]: each
+    /// selected `range_max` SHOULD exceed its divisor, otherwise a number
+    /// beyond the last unit is not saturated by the markers and shown
+    /// unscaled.
+    pub const fn with_unit_system<S: UnitSystem<'a> + Copy>(self, unit_system: S) -> Formatter<'a, S> {
+        Formatter {
+            separator: self.separator,
+            unit_system,
+            custom_unit: self.custom_unit,
+            markers: self.markers,
+            overflow: self.overflow,
+            notation: self.notation,
+            exponent_style: self.exponent_style,
+            placeholders: self.placeholders,
+            sign_style: self.sign_style,
+            locale: self.locale,
+            digit_set: self.digit_set,
+            bidi: self.bidi,
+            placement: self.placement,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the smallest unit used, given by its index or its name, going into
+    /// fractions below it. Returns `None` if no such unit is found.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use humat::Formatter;
+    ///
+    /// let formatter = Formatter::BINARY
+    ///     .with_custom_unit("B")
+    ///     .with_min_unit("Ki")
+    ///     .unwrap()
+    ///     .with_max_unit("Ti")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(formatter.format(512).to_string(), "0.50 KiB");
+    /// assert_eq!(formatter.format(1_536).to_string(), "1.50 KiB");
+    /// assert_eq!(
+    ///     formatter.format(2_251_799_813_685_248_u64).to_string(),
+    ///     "2048.00 TiB"
+    /// );
+    /// ```
+    pub fn with_min_unit<'b>(self, unit: impl Into<UnitRef<'b>>) -> Option<Self> {
+        let min_unit = self.unit_index(unit)?;
+
+        Some(Self {
+            unit_system: self.unit_system.with_min_unit(min_unit),
+            ..self
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the largest unit used, given by its index or its name, growing the
+    /// number above it regardless of the [`Overflow`] policy. Returns `None`
+    /// if no such unit is found.
+    ///
+    /// See [`Formatter::with_min_unit`].
+    pub fn with_max_unit<'b>(self, unit: impl Into<UnitRef<'b>>) -> Option<Self> {
+        let max_unit = self.unit_index(unit)?;
+
+        Some(Self {
+            unit_system: self.unit_system.with_max_unit(max_unit),
+            ..self
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the fraction of the next `range_max` from which the next unit is
+    /// used, `1.0` by default, for the integer and `f64` paths alike.
+    ///
+    /// Below `1.0`, units switch earlier, e.g. `0.95` shows `980_000` as
+    /// `0.98 M`. Above `1.0`, units switch later, e.g. `1.05` shows
+    /// `1_020_000` as `1020.00 K`. The factor SHOULD be positive.
+    ///
+    /// The last unit is kept up to its `range_max`, as there is no unit to
+    /// promote into, and the [`Overflow`] policy applies beyond it.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use humat::Formatter;
+    ///
+    /// let formatter = Formatter::SI.with_promotion(0.95);
+    /// assert_eq!(formatter.format(980_000).to_string(), "0.98 M");
+    /// assert_eq!(formatter.format(980_000.0).to_string(), "0.98 M");
+    /// assert_eq!(formatter.format(940_000).to_string(), "940.00 K");
+    ///
+    /// let formatter = Formatter::SI.with_promotion(1.05);
+    /// assert_eq!(formatter.format(1_020_000).to_string(), "1020.00 K");
+    /// ```
+    pub const fn with_promotion(self, promotion: f64) -> Self {
+        Self {
+            unit_system: self.unit_system.with_promotion(promotion),
+            ..self
         }
     }
 
+    #[inline]
+    #[must_use]
+    /// Sets the decimal places of each unit, in ascending order, overriding
+    /// `DECIMAL_PLACES` of [`Formatted`], e.g. `ls -h` style output.
+    ///
+    /// Units beyond the given slice keep `DECIMAL_PLACES`. Integers in the
+    /// unit of one are still shown without fraction. An explicit
+    /// [`Formatted::with_decimal_places`] takes precedence over these.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use humat::Formatter;
+    ///
+    /// let formatter = Formatter::BINARY
+    ///     .with_custom_unit("B")
+    ///     .with_unit_decimal_places(&[0, 1, 1]);
+    ///
+    /// assert_eq!(formatter.format(512).to_string(), "512 B");
+    /// assert_eq!(formatter.format(512.5).to_string(), "512 B");
+    /// assert_eq!(formatter.format(1_536).to_string(), "1.5 KiB");
+    /// assert_eq!(formatter.format(5_767_168).to_string(), "5.5 MiB");
+    /// assert_eq!(formatter.format(5_905_580_032_u64).to_string(), "5.50 GiB");
+    /// assert_eq!(
+    ///     formatter
+    ///         .format(1_536)
+    ///         .with_decimal_places::<2>()
+    ///         .to_string(),
+    ///     "1.50 KiB"
+    /// );
+    /// ```
+    pub const fn with_unit_decimal_places(self, decimal_places: &'a [u8]) -> Self {
+        Self {
+            unit_system: self.unit_system.with_decimal_places(decimal_places),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Formats an unsigned integer, with default 2 decimal places.
+    ///
+    /// The typed `format_*` methods are const, and only available with the
+    /// default [`Ladder`]; other unit systems format through
+    /// [`Formatter::format`].
+    pub const fn format_uint(&self, target: u128) -> Formatted<'a> {
+        self.format_uint_fixed_dp(target)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Formats an unsigned integer, with fixed `DECIMAL_PLACES`.
+    pub const fn format_uint_fixed_dp<const DECIMAL_PLACES: usize>(
+        &self,
+        target: u128,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        self.format_uint_selected(target, self.unit_system.select_u128(target))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Formats a signed integer, with default 2 decimal places.
+    pub const fn format_int(&self, target: i128) -> Formatted<'a> {
+        self.format_int_fixed_dp(target)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Formats a signed integer, with fixed `DECIMAL_PLACES`.
+    pub const fn format_int_fixed_dp<const DECIMAL_PLACES: usize>(
+        &self,
+        target: i128,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        let magnitude = target.unsigned_abs();
+
+        self.format_uint_selected(magnitude, self.unit_system.select_u128(magnitude))
+            .with_sign(target >= 0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Formats an `f64`, with default 2 decimal places.
+    pub const fn format_double(&self, target: f64) -> Formatted<'a> {
+        self.format_double_fixed_dp(target)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Formats an `f64`, with fixed `DECIMAL_PLACES`.
+    pub const fn format_double_fixed_dp<const DECIMAL_PLACES: usize>(
+        &self,
+        target: f64,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        if let Some(formatted) = self.format_special(target) {
+            return formatted;
+        }
+
+        // Scales by magnitude like the signed integers, keeping the sign of `-0.0`.
+        let positive = !target.is_sign_negative();
+        let magnitude = if positive { target } else { -target };
+
+        self.format_double_selected(magnitude, self.unit_system.select_f64(magnitude))
+            .with_sign(positive)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Formats a number in the given unit regardless of its magnitude, with
+    /// default 2 decimal places, e.g. for the rows of a table column.
+    ///
+    /// The unit is given by its index or its name, where an empty name means
+    /// no unit. Returns `None` if no such unit is found.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use humat::Formatter;
+    ///
+    /// let formatter = Formatter::BINARY.with_custom_unit("B");
+    ///
+    /// let column = [53_687_092_u64, 1_288_490_189, 32_212_254_720]
+    ///     .map(|value| formatter.format_in_unit(value, "Gi").unwrap().to_string());
+    /// assert_eq!(column, ["0.05 GiB", "1.20 GiB", "30.00 GiB"]);
+    ///
+    /// assert_eq!(
+    ///     formatter.format_in_unit(1_536, 0).unwrap().to_string(),
+    ///     "1536 B"
+    /// );
+    /// assert!(formatter.format_in_unit(1_536, "Xi").is_none());
+    /// ```
+    pub fn format_in_unit<'b>(&self, target: impl Humat, unit: impl Into<UnitRef<'b>>) -> Option<Formatted<'a>> {
+        self.format_in_unit_fixed_dp(target, unit)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Formats a number in the given unit regardless of its magnitude, with
+    /// fixed `DECIMAL_PLACES`.
+    ///
+    /// See [`Formatter::format_in_unit`].
+    pub fn format_in_unit_fixed_dp<'b, const DECIMAL_PLACES: usize>(
+        &self,
+        target: impl Humat,
+        unit: impl Into<UnitRef<'b>>,
+    ) -> Option<Formatted<'a, DECIMAL_PLACES>> {
+        Some(self.pin_unit(unit)?.format_fixed_dp(target))
+    }
+
+    #[must_use]
+    /// Returns a formatter always using the given unit, given by its index or
+    /// its name. Returns `None` if no such unit is found.
+    ///
+    /// See [`Formatter::format_in_unit`].
+    pub fn pin_unit<'b>(&self, unit: impl Into<UnitRef<'b>>) -> Option<Formatter<'a, Pinned<'a>>> {
+        let selection = self.unit_system.selection(self.unit_index(unit)?)?;
+
+        Some(self.with_unit_system(Pinned::new(selection)))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    /// Returns a formatter always using the unit chosen for a set of values,
+    /// e.g. the cells of a table column or a chart series.
+    ///
+    /// Missing and non-finite values are ignored, and the first unit is used
+    /// for an empty set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use humat::format::PinBy;
+    /// use humat::Formatter;
+    ///
+    /// let values = [1_536_u64, 52_428_800, 1_288_490_189];
+    ///
+    /// let formatter = Formatter::BINARY.pin(values, PinBy::Max);
+    /// let column = values.map(|value| formatter.format(value).to_string());
+    /// assert_eq!(column, ["0.00 Gi", "0.04 Gi", "1.20 Gi"]);
+    ///
+    /// let formatter = Formatter::BINARY.pin(values, PinBy::Median);
+    /// let column = values.map(|value| formatter.format(value).to_string());
+    /// assert_eq!(column, ["0.00 Mi", "50.00 Mi", "1228.80 Mi"]);
+    /// ```
+    pub fn pin<T: Humat>(&self, values: impl IntoIterator<Item = T>, by: PinBy) -> Formatter<'a, Pinned<'a>> {
+        // The units are ordered, so is the index of the unit of each value.
+        let mut indices: Vec<usize> = values
+            .into_iter()
+            .map(|value| value.magnitude())
+            .filter(|magnitude| magnitude.is_finite())
+            .map(|magnitude| self.unit_system.unit_index(magnitude))
+            .collect();
+
+        let idx = match indices.len() {
+            0 => self.unit_system.unit_index(0.0),
+            len => {
+                let rank = by.rank(len);
+                *indices.select_nth_unstable(rank).1
+            }
+        };
+
+        match self.pin_unit(idx) {
+            Some(formatter) => formatter,
+            // No units, which `Formatter::custom` rejects.
+            None => self.with_unit_system(Pinned::new(self.unit_system.select(0.0, true))),
+        }
+    }
+
+    /// Returns the index of the unit given by its index or its name, if any.
+    fn unit_index<'b>(&self, unit: impl Into<UnitRef<'b>>) -> Option<usize> {
+        let ranged_units = self.unit_system.ranged_units();
+
+        match unit.into() {
+            UnitRef::Index(idx) => (idx < ranged_units.len()).then_some(idx),
+            UnitRef::Name(name) => ranged_units
+                .iter()
+                .position(|ranged_unit| ranged_unit.unit.unwrap_or_default() == name),
+        }
+    }
+}
+
+impl<'a, S: UnitSystem<'a> + Copy> Formatter<'a, S> {
     #[inline]
     #[must_use]
     /// Sets the separator between numbers and units.
-    pub const fn with_separator(self, separator: &'static str) -> Self {
+    pub const fn with_separator(self, separator: &'a str) -> Self {
         Self { separator, ..self }
     }
 
     #[inline]
     #[must_use]
     /// Set custom unit attached after the abbreviated number's unit.
-    pub const fn with_custom_unit(self, custom_unit: &'static str) -> Self {
+    pub const fn with_custom_unit(self, custom_unit: &'a str) -> Self {
         Self {
             custom_unit: Some(custom_unit),
             ..self
         }
     }
 
+    #[inline]
+    #[must_use]
+    /// Enables the approximation and saturation [`Markers`].
+    ///
+    /// With markers enabled, a number exceeding the last unit saturates to the
+    /// largest number the last unit can show, e.g. `>999.99 Q`, instead of
+    /// being printed without unit.
+    pub const fn with_markers(self, markers: Markers<'a>) -> Self {
+        Self {
+            markers: Some(markers),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`Overflow`] policy for numbers exceeding the last unit.
+    pub const fn with_overflow(self, overflow: Overflow) -> Self {
+        Self { overflow, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`Notation`], scaling with the ranged units by default.
+    pub const fn with_notation(self, notation: Notation) -> Self {
+        Self { notation, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`ExponentStyle`] of scientific or engineering notation, used
+    /// by both [`Notation`] and [`Overflow`].
+    pub const fn with_exponent_style(self, exponent_style: ExponentStyle) -> Self {
+        Self { exponent_style, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`Placeholders`] for non-finite, negative zero and missing
+    /// numbers.
+    pub const fn with_placeholders(self, placeholders: Placeholders<'a>) -> Self {
+        Self { placeholders, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`SignStyle`], ASCII hyphen-minus for negative numbers by
+    /// default.
+    pub const fn with_sign_style(self, sign_style: SignStyle<'a>) -> Self {
+        Self { sign_style, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`Locale`] of the decimal mark and digit grouping, ASCII
+    /// period without grouping by default, except for the localized presets.
+    pub const fn with_locale(self, locale: Locale<'a>) -> Self {
+        Self { locale, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`DigitSet`] the number is transliterated into, ASCII digits
+    /// by default.
+    pub const fn with_digit_set(self, digit_set: DigitSet<'a>) -> Self {
+        Self { digit_set, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`Bidi`] controls for right-to-left contexts, none by default.
+    pub const fn with_bidi(self, bidi: Bidi) -> Self {
+        Self { bidi, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the [`Placement`] of prefix, suffix and units, e.g. for currency
+    /// symbols like `$1.20 M`.
+    pub const fn with_placement(self, placement: Placement<'a>) -> Self {
+        Self { placement, ..self }
+    }
+
     #[inline]
     #[must_use]
     /// Formats a number, with default 2 decimal places.
     ///
     /// Any number type that implements the [`Humat`] trait is supported.
-    pub fn format(&self, target: impl Humat) -> Formatted {
+    pub fn format(&self, target: impl Humat) -> Formatted<'a> {
         target.humat(self)
     }
 
@@ -132,152 +658,414 @@ impl<const N: usize> Formatter<N> {
     /// Formats a number, with fixed `DECIMAL_PLACES`.
     ///
     /// Any number type that implements the [`Humat`] trait is supported.
-    pub fn format_fixed_dp<const DECIMAL_PLACES: usize>(&self, target: impl Humat) -> Formatted<DECIMAL_PLACES> {
+    pub fn format_fixed_dp<const DECIMAL_PLACES: usize>(&self, target: impl Humat) -> Formatted<'a, DECIMAL_PLACES> {
         target.humat_fixed_dp(self)
     }
-}
 
-impl<const N: usize> Formatter<N> {
     #[inline]
-    #[must_use]
-    /// Formats an unsigned integer, with default 2 decimal places.
-    pub const fn format_uint(&self, target: u128) -> Formatted {
-        self.format_uint_fixed_dp(target)
+    /// Formats a number, with default 2 decimal places.
+    ///
+    /// Any number type that implements the [`Humat`] trait is supported.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`OverflowError`] if the number exceeds the last unit and the
+    /// overflow policy is [`Overflow::Error`].
+    pub fn try_format(&self, target: impl Humat) -> Result<Formatted<'a>, OverflowError> {
+        target.humat(self).checked()
+    }
+
+    #[inline]
+    /// Formats a number, with fixed `DECIMAL_PLACES`.
+    ///
+    /// Any number type that implements the [`Humat`] trait is supported.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Formatter::try_format`].
+    pub fn try_format_fixed_dp<const DECIMAL_PLACES: usize>(
+        &self,
+        target: impl Humat,
+    ) -> Result<Formatted<'a, DECIMAL_PLACES>, OverflowError> {
+        target.humat_fixed_dp(self).checked()
     }
 
     #[inline]
     #[must_use]
-    /// Formats an unsigned integer, with fixed `DECIMAL_PLACES`.
-    pub const fn format_uint_fixed_dp<const DECIMAL_PLACES: usize>(&self, target: u128) -> Formatted<DECIMAL_PLACES> {
-        if target < self.ranged_units[0].range_max.get() {
-            return Formatted {
-                number: FormattedImpl::Int {
-                    positive: true,
-                    integer: target,
-                    unit: self.ranged_units[0].unit,
-                },
-                separator: self.separator,
-                custom_unit: self.custom_unit,
-            };
+    /// Formats an amount of money in minor units, e.g. cents, with default 2
+    /// decimal places when compacted.
+    ///
+    /// Amounts below the [compact threshold](Currency::with_compact_threshold)
+    /// are shown with the currency's fraction digits, e.g. `$1,234.50`, and
+    /// larger amounts are scaled with the ranged units, e.g. `$1.20 M`. The
+    /// digits are computed from the integer amount, without `f64`
+    /// conversion.
+    ///
+    /// The currency symbol is used as the prefix, unless the [`Placement`]
+    /// has one.
+    pub fn format_money(&self, minor_units: i128, currency: Currency) -> Formatted<'a> {
+        self.format_money_fixed_dp(minor_units, currency)
+    }
+
+    #[must_use]
+    /// Formats an amount of money in minor units, e.g. cents, with fixed
+    /// `DECIMAL_PLACES` when compacted.
+    ///
+    /// See [`Formatter::format_money`].
+    pub fn format_money_fixed_dp<const DECIMAL_PLACES: usize>(
+        &self,
+        minor_units: i128,
+        currency: Currency,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        let mut formatted = self
+            .format_money_magnitude(minor_units.unsigned_abs(), minor_units >= 0, currency)
+            .with_sign(minor_units >= 0);
+
+        if formatted.placement.prefix.is_empty() {
+            formatted.placement.prefix = currency.symbol;
         }
 
-        let mut idx = 1;
-        // Precision loss for very large numbers
-        let number_precision_max = self.ranged_units[0].range_max.get() as f64
-            - self.ranged_units[0].range_max.get() as f64 * 0.000_000_000_000_01;
+        formatted
+    }
 
-        while idx < N {
-            if target < self.ranged_units[idx].range_max.get() {
-                let base = self.ranged_units[idx - 1].range_max.get();
+    #[inline]
+    /// Formats an unsigned integer through the [`UnitSystem`], with fixed
+    /// `DECIMAL_PLACES`.
+    fn uint_fixed_dp<const DECIMAL_PLACES: usize>(&self, target: u128) -> Formatted<'a, DECIMAL_PLACES> {
+        self.format_uint_selected(target, self.unit_system.select_uint(target, true))
+    }
 
-                let integer_part = target / base;
-                let leftover = target % base;
+    #[inline]
+    /// Formats a signed integer through the [`UnitSystem`], with fixed
+    /// `DECIMAL_PLACES`.
+    fn int_fixed_dp<const DECIMAL_PLACES: usize>(&self, target: i128) -> Formatted<'a, DECIMAL_PLACES> {
+        let magnitude = target.unsigned_abs();
 
-                let fractional_part = leftover as f64 / base as f64;
+        self.format_uint_selected(magnitude, self.unit_system.select_uint(magnitude, target >= 0))
+            .with_sign(target >= 0)
+    }
 
-                let number = integer_part as f64 + fractional_part;
-                let number = if number >= number_precision_max {
-                    number_precision_max
-                } else {
-                    number
-                };
-
-                return Formatted {
-                    number: FormattedImpl::F64 {
-                        number,
-                        unit: self.ranged_units[idx].unit,
-                    },
-                    separator: self.separator,
-                    custom_unit: self.custom_unit,
-                };
-            }
+    #[inline]
+    /// Formats an `f64` through the [`UnitSystem`], with fixed
+    /// `DECIMAL_PLACES`.
+    fn double_fixed_dp<const DECIMAL_PLACES: usize>(&self, target: f64) -> Formatted<'a, DECIMAL_PLACES> {
+        if let Some(formatted) = self.format_special(target) {
+            return formatted;
+        }
+
+        // Scales by magnitude like the signed integers, keeping the sign of `-0.0`.
+        let positive = !target.is_sign_negative();
+        let magnitude = if positive { target } else { -target };
+
+        self.format_double_selected(magnitude, self.unit_system.select(magnitude, positive))
+            .with_sign(positive)
+    }
 
-            idx += 1;
+    /// Formats the magnitude of an amount of money in minor units.
+    fn format_money_magnitude<const DECIMAL_PLACES: usize>(
+        &self,
+        minor_units: u128,
+        positive: bool,
+        currency: Currency,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        let Some(minor_per_major) = currency.minor_per_major() else {
+            // The amount is below one major unit for so many fraction digits.
+            let amount = minor_units as f64 / pow10(currency.fraction_digits as usize);
+
+            return self.format_double_selected(amount, self.unit_system.select(amount, positive));
+        };
+        let major_units = minor_units / minor_per_major;
+
+        if !matches!(self.notation, Notation::Units) {
+            return self.money_exponent(minor_units, currency, matches!(self.notation, Notation::Engineering));
         }
 
-        Formatted {
-            number: FormattedImpl::F64 {
-                number: target as f64,
-                unit: None,
+        // Below the compact threshold, the unit of zero is used.
+        let selection = match currency.compact_threshold {
+            Some(compact_threshold) if major_units < compact_threshold => self.unit_system.select_uint(0, positive),
+            _ => self.unit_system.select_uint(major_units, positive),
+        };
+
+        let amount = major_units as f64 + (minor_units % minor_per_major) as f64 / minor_per_major as f64;
+
+        let (divisor, ranged_unit, decimal_places) = match selection {
+            Selection::Unit {
+                divisor,
+                ranged_unit,
+                decimal_places,
+            } => (divisor, ranged_unit, decimal_places),
+            Selection::Overflow {
+                divisor,
+                ranged_unit,
+                decimal_places,
+            } => match self.overflow {
+                Overflow::Extend => (divisor, ranged_unit, decimal_places),
+                Overflow::Unscaled if self.markers.is_none() => {
+                    return self.formatted(FormattedImpl::Minor {
+                        positive: true,
+                        minor_units,
+                        divisor: minor_per_major,
+                        fraction_digits: Some(currency.fraction_digits),
+                        unit: None,
+                    });
+                }
+                Overflow::Scientific => return self.money_exponent(minor_units, currency, false),
+                Overflow::Engineering => return self.money_exponent(minor_units, currency, true),
+                // Saturated or reported.
+                Overflow::Unscaled | Overflow::Error => return self.format_double_selected(amount, selection),
             },
-            separator: self.separator,
-            custom_unit: self.custom_unit,
+        };
+
+        match divisor.get() {
+            Some(1) => {
+                return self.formatted(FormattedImpl::Minor {
+                    positive: true,
+                    minor_units,
+                    divisor: minor_per_major,
+                    fraction_digits: Some(currency.fraction_digits),
+                    unit: ranged_unit.unit,
+                });
+            }
+            // The digits are computed by multiplying the remainder by 10.
+            Some(base) => {
+                if let Some(divisor) = base.checked_mul(minor_per_major * 10) {
+                    return self.formatted(FormattedImpl::Minor {
+                        positive: true,
+                        minor_units,
+                        divisor: divisor / 10,
+                        fraction_digits: decimal_places,
+                        unit: ranged_unit.unit,
+                    });
+                }
+            }
+            None => {}
         }
+
+        // The divisor exceeds `u128`, where the amount is shown to the
+        // precision of `f64` like any other number.
+        self.format_double_selected(amount, selection)
     }
 
     #[inline]
-    #[must_use]
-    /// Formats a signed integer, with default 2 decimal places.
-    pub const fn format_int(&self, target: i128) -> Formatted {
-        self.format_uint_fixed_dp(target.unsigned_abs()).with_sign(target >= 0)
+    /// Formats an amount of money in minor units in scientific or engineering
+    /// notation, from the integer amount.
+    const fn money_exponent<const DECIMAL_PLACES: usize>(
+        &self,
+        minor_units: u128,
+        currency: Currency,
+        engineering: bool,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        self.formatted(FormattedImpl::MinorExponent {
+            positive: true,
+            minor_units,
+            fraction_digits: currency.fraction_digits,
+            engineering,
+            style: self.exponent_style,
+        })
     }
 
-    #[inline]
-    #[must_use]
-    /// Formats a signed integer, with fixed `DECIMAL_PLACES`.
-    pub const fn format_int_fixed_dp<const DECIMAL_PLACES: usize>(&self, target: i128) -> Formatted<DECIMAL_PLACES> {
-        self.format_uint_fixed_dp(target.unsigned_abs()).with_sign(target >= 0)
+    /// Formats the magnitude of an integer in the selected unit, with fixed
+    /// `DECIMAL_PLACES`.
+    const fn format_uint_selected<const DECIMAL_PLACES: usize>(
+        &self,
+        target: u128,
+        selection: Selection<'a>,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        if !matches!(self.notation, Notation::Units) {
+            return self.exponent(target as f64, matches!(self.notation, Notation::Engineering));
+        }
+
+        match selection {
+            Selection::Unit {
+                divisor,
+                ranged_unit,
+                decimal_places,
+            } => match divisor.get() {
+                Some(1) => self.formatted(FormattedImpl::Int {
+                    positive: true,
+                    integer: target,
+                    unit: ranged_unit.unit,
+                }),
+                Some(base) => {
+                    let limit = ranged_unit.range_max.ratio(&divisor);
+                    // Precision loss for very large numbers
+                    let number_precision_max = limit as f64 - limit as f64 * 0.000_000_000_000_01;
+
+                    let number = (target / base) as f64 + (target % base) as f64 / base as f64;
+
+                    self.formatted(FormattedImpl::Scaled {
+                        // A unit given by `format_in_unit` may be exceeded.
+                        number: if target / base < limit && number > number_precision_max {
+                            number_precision_max
+                        } else {
+                            number
+                        },
+                        remainder: target % base,
+                        divisor: base,
+                        decimal_places,
+                        unit: ranged_unit.unit,
+                    })
+                }
+                None => self.formatted(FormattedImpl::F64 {
+                    number: target as f64 / divisor.to_f64(),
+                    decimal_places,
+                    unit: ranged_unit.unit,
+                }),
+            },
+            Selection::Overflow {
+                divisor,
+                ranged_unit,
+                decimal_places,
+            } => {
+                if let (Overflow::Extend, Some(base)) = (self.overflow, divisor.get()) {
+                    return self.formatted(FormattedImpl::Scaled {
+                        number: (target / base) as f64 + (target % base) as f64 / base as f64,
+                        remainder: target % base,
+                        divisor: base,
+                        decimal_places,
+                        unit: ranged_unit.unit,
+                    });
+                }
+
+                self.overflowed(target as f64, divisor, ranged_unit, decimal_places)
+            }
+        }
     }
 
-    #[inline]
-    #[must_use]
-    /// Formats an `f64`, with default 2 decimal places.
-    pub const fn format_double(&self, target: f64) -> Formatted {
-        self.format_double_fixed_dp(target)
+    /// Formats a finite, non-negative `f64` in the selected unit, with fixed
+    /// `DECIMAL_PLACES`.
+    const fn format_double_selected<const DECIMAL_PLACES: usize>(
+        &self,
+        target: f64,
+        selection: Selection<'a>,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        if !matches!(self.notation, Notation::Units) {
+            return self.exponent(target, matches!(self.notation, Notation::Engineering));
+        }
+
+        match selection {
+            Selection::Unit {
+                divisor,
+                ranged_unit,
+                decimal_places,
+            } => self.formatted(FormattedImpl::F64 {
+                number: target / divisor.to_f64(),
+                decimal_places,
+                unit: ranged_unit.unit,
+            }),
+            Selection::Overflow {
+                divisor,
+                ranged_unit,
+                decimal_places,
+            } => self.overflowed(target, divisor, ranged_unit, decimal_places),
+        }
     }
 
-    #[inline]
-    #[must_use]
-    /// Formats an `f64`, with fixed `DECIMAL_PLACES`.
-    pub const fn format_double_fixed_dp<const DECIMAL_PLACES: usize>(&self, target: f64) -> Formatted<DECIMAL_PLACES> {
+    /// Formats a non-finite `f64`, or `-0.0` with its placeholder, if any.
+    const fn format_special<const DECIMAL_PLACES: usize>(&self, target: f64) -> Option<Formatted<'a, DECIMAL_PLACES>> {
         if !target.is_finite() {
-            return Formatted {
-                number: FormattedImpl::F64 {
-                    number: target,
-                    unit: None,
-                },
-                separator: self.separator,
-                custom_unit: self.custom_unit,
+            let placeholder = if target.is_nan() {
+                self.placeholders.nan
+            } else if target > 0.0 {
+                self.placeholders.infinity
+            } else {
+                self.placeholders.neg_infinity
             };
-        }
 
-        if target < self.ranged_units[0].range_max.get() as f64 {
-            return Formatted {
-                number: FormattedImpl::F64 {
+            return Some(match placeholder {
+                Some(placeholder) => self.placeholder(target, placeholder),
+                None => self.formatted(FormattedImpl::F64 {
                     number: target,
-                    unit: self.ranged_units[0].unit,
-                },
-                separator: self.separator,
-                custom_unit: self.custom_unit,
-            };
+                    decimal_places: None,
+                    unit: None,
+                }),
+            });
         }
 
-        let mut idx = 1;
-
-        while idx < N {
-            if target < self.ranged_units[idx].range_max.get() as f64 {
-                return Formatted {
-                    number: FormattedImpl::F64 {
-                        number: target / self.ranged_units[idx - 1].range_max.get() as f64,
-                        unit: self.ranged_units[idx].unit,
-                    },
-                    separator: self.separator,
-                    custom_unit: self.custom_unit,
-                };
-            }
-
-            idx += 1;
+        match (target == 0.0 && target.is_sign_negative(), self.placeholders.neg_zero) {
+            (true, Some(neg_zero)) => Some(self.placeholder(target, neg_zero)),
+            _ => None,
         }
+    }
 
+    #[inline]
+    /// Wraps the number with the options of this formatter.
+    const fn formatted<const DECIMAL_PLACES: usize>(&self, number: FormattedImpl<'a>) -> Formatted<'a, DECIMAL_PLACES> {
         Formatted {
-            number: FormattedImpl::F64 {
-                number: target,
-                unit: None,
-            },
+            number,
             separator: self.separator,
             custom_unit: self.custom_unit,
+            markers: self.markers,
+            sign_style: self.sign_style,
+            locale: self.locale,
+            digit_set: self.digit_set,
+            bidi: self.bidi,
+            placement: self.placement,
         }
     }
+
+    #[inline]
+    /// Renders the placeholder instead of the number.
+    const fn placeholder<const DECIMAL_PLACES: usize>(
+        &self,
+        number: f64,
+        placeholder: &'a str,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        self.formatted(FormattedImpl::Placeholder { number, placeholder })
+    }
+
+    /// Formats a number exceeding the last unit, following the [`Overflow`]
+    /// policy.
+    const fn overflowed<const DECIMAL_PLACES: usize>(
+        &self,
+        target: f64,
+        divisor: Magnitude,
+        ranged_unit: RangedUnit<'a>,
+        decimal_places: Option<u8>,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        let number = match self.overflow {
+            Overflow::Unscaled => {
+                // The largest number the last unit can show, none for units
+                // not checked like `Formatter::custom`.
+                let limit = ranged_unit.range_max.ratio(&divisor);
+
+                if self.markers.is_some() && limit > 0 {
+                    FormattedImpl::Saturated {
+                        positive: true,
+                        limit,
+                        decimal_places,
+                        unit: ranged_unit.unit,
+                    }
+                } else {
+                    FormattedImpl::Unscaled { number: target }
+                }
+            }
+            Overflow::Extend => FormattedImpl::F64 {
+                number: round_significant(target / divisor.to_f64()),
+                decimal_places,
+                unit: ranged_unit.unit,
+            },
+            Overflow::Scientific => return self.exponent(target, false),
+            Overflow::Engineering => return self.exponent(target, true),
+            Overflow::Error => FormattedImpl::Overflowed { number: target },
+        };
+
+        self.formatted(number)
+    }
+
+    #[inline]
+    /// Formats a finite number in scientific or engineering notation.
+    const fn exponent<const DECIMAL_PLACES: usize>(
+        &self,
+        target: f64,
+        engineering: bool,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        self.formatted(FormattedImpl::Exponent {
+            number: target,
+            engineering,
+            style: self.exponent_style,
+        })
+    }
 }
 
 // === Humat ===
@@ -286,32 +1074,92 @@ impl<const N: usize> Formatter<N> {
 pub trait Humat {
     #[must_use]
     /// Formats the number, with default 2 decimal places.
-    fn humat<const N: usize>(self, formatter: &Formatter<N>) -> Formatted;
+    fn humat<'a, S: UnitSystem<'a> + Copy>(self, formatter: &Formatter<'a, S>) -> Formatted<'a>;
 
     #[must_use]
     /// Formats the number, with fixed `DECIMAL_PLACES`.
-    fn humat_fixed_dp<const DECIMAL_PLACES: usize, const N: usize>(
+    fn humat_fixed_dp<'a, S: UnitSystem<'a> + Copy, const DECIMAL_PLACES: usize>(
         self,
-        formatter: &Formatter<N>,
-    ) -> Formatted<DECIMAL_PLACES>;
+        formatter: &Formatter<'a, S>,
+    ) -> Formatted<'a, DECIMAL_PLACES>;
+
+    #[must_use]
+    /// Returns the absolute value as an `f64`, NaN if missing.
+    ///
+    /// NaN by default, i.e. the value is ignored by [`Formatter::pin`].
+    fn magnitude(&self) -> f64 {
+        f64::NAN
+    }
+}
+
+/// Converts a number into the type it is formatted as, with `From` where the
+/// conversion is lossless on every platform.
+macro_rules! widen {
+    (usize, $cty:ident, $target:expr) => {
+        $target as $cty
+    };
+    (isize, $cty:ident, $target:expr) => {
+        $target as $cty
+    };
+    (u128,u128, $target:expr) => {
+        $target
+    };
+    (i128,i128, $target:expr) => {
+        $target
+    };
+    (f64,f64, $target:expr) => {
+        $target
+    };
+    ($ty:ident, $cty:ident, $target:expr) => {
+        $cty::from($target)
+    };
+}
+
+/// Converts a number into an `f64`, with `From` where the conversion is
+/// lossless.
+macro_rules! to_f64 {
+    (u128, $target:expr) => {
+        $target as f64
+    };
+    (u64, $target:expr) => {
+        $target as f64
+    };
+    (usize, $target:expr) => {
+        $target as f64
+    };
+    (i128, $target:expr) => {
+        $target as f64
+    };
+    (i64, $target:expr) => {
+        $target as f64
+    };
+    (isize, $target:expr) => {
+        $target as f64
+    };
+    (f64, $target:expr) => {
+        $target
+    };
+    ($ty:ident, $target:expr) => {
+        f64::from($target)
+    };
 }
 
 macro_rules! impl_number {
     ($fty:ident $cty:ident => $($ty:ident)*) => {
-        impl<const N: usize> Formatter<N> {
+        impl<'a> Formatter<'a> {
             $(
                 pastey::paste! {
                     #[inline]
                     #[must_use]
                     #[doc = concat!("Formats ", stringify!($ty), ", with default 2 decimal places.")]
-                    pub const fn [<format_ $ty>](&self, target: $ty) -> Formatted {
+                    pub const fn [<format_ $ty>](&self, target: $ty) -> Formatted<'a> {
                         self.[<format_ $fty _fixed_dp>](target as $cty)
                     }
 
                     #[inline]
                     #[must_use]
                     #[doc = concat!("Formats ", stringify!($ty), ", with fixed `DECIMAL_PLACES`.")]
-                    pub const fn [<format_ $ty _fixed_dp>]<const DECIMAL_PLACES: usize>(&self, target: $ty) -> Formatted<DECIMAL_PLACES> {
+                    pub const fn [<format_ $ty _fixed_dp>]<const DECIMAL_PLACES: usize>(&self, target: $ty) -> Formatted<'a, DECIMAL_PLACES> {
                         self.[<format_ $fty _fixed_dp>](target as $cty)
                     }
                 }
@@ -322,16 +1170,21 @@ macro_rules! impl_number {
             impl Humat for $ty {
                 pastey::paste! {
                     #[inline]
-                    fn humat<const N: usize>(self, formatter: &Formatter<N>) -> Formatted {
-                        formatter.[<format_ $ty>](self)
+                    fn humat<'a, S: UnitSystem<'a> + Copy>(self, formatter: &Formatter<'a, S>) -> Formatted<'a> {
+                        formatter.[<$fty _fixed_dp>](widen!($ty, $cty, self))
                     }
 
                     #[inline]
-                    fn humat_fixed_dp<const DECIMAL_PLACES: usize, const N: usize>(
+                    fn humat_fixed_dp<'a, S: UnitSystem<'a> + Copy, const DECIMAL_PLACES: usize>(
                         self,
-                        formatter: &Formatter<N>,
-                    ) -> Formatted<DECIMAL_PLACES> {
-                        formatter.[<format_ $ty _fixed_dp>](self)
+                        formatter: &Formatter<'a, S>,
+                    ) -> Formatted<'a, DECIMAL_PLACES> {
+                        formatter.[<$fty _fixed_dp>](widen!($ty, $cty, self))
+                    }
+
+                    #[inline]
+                    fn magnitude(&self) -> f64 {
+                        to_f64!($ty, *self).abs()
                     }
                 }
             }
@@ -343,11 +1196,36 @@ impl_number!(uint u128 => usize u128 u64 u32 u16 u8);
 impl_number!(int i128 => isize i128 i64 i32 i16 i8);
 impl_number!(double f64 => f64 f32);
 
+impl<T: Humat> Humat for Option<T> {
+    #[inline]
+    /// Formats the number, or renders [`Placeholders::missing`] for `None`.
+    fn humat<'a, S: UnitSystem<'a> + Copy>(self, formatter: &Formatter<'a, S>) -> Formatted<'a> {
+        self.humat_fixed_dp(formatter)
+    }
+
+    #[inline]
+    /// Formats the number, or renders [`Placeholders::missing`] for `None`.
+    fn humat_fixed_dp<'a, S: UnitSystem<'a> + Copy, const DECIMAL_PLACES: usize>(
+        self,
+        formatter: &Formatter<'a, S>,
+    ) -> Formatted<'a, DECIMAL_PLACES> {
+        match self {
+            Some(target) => target.humat_fixed_dp(formatter),
+            None => formatter.placeholder(f64::NAN, formatter.placeholders.missing),
+        }
+    }
+
+    #[inline]
+    fn magnitude(&self) -> f64 {
+        self.as_ref().map_or(f64::NAN, Humat::magnitude)
+    }
+}
+
 // === Formatted ===
 
 #[derive(Debug)]
 /// The number to be formatted.
-enum FormattedImpl {
+enum FormattedImpl<'a> {
     /// An integer with an optional fractional part.
     Int {
         /// Whether the number is positive.
@@ -357,7 +1235,25 @@ enum FormattedImpl {
         integer: u128,
 
         /// The abbreviated number's unit.
-        unit: Option<&'static str>,
+        unit: Option<&'a str>,
+    },
+
+    /// An integer divided by the base of its unit.
+    Scaled {
+        /// The quotient, as an `f64`.
+        number: f64,
+
+        /// The exact remainder of the division.
+        remainder: u128,
+
+        /// The divisor, i.e. the base of the unit.
+        divisor: u128,
+
+        /// The decimal places of the unit, overriding `DECIMAL_PLACES`.
+        decimal_places: Option<u8>,
+
+        /// The abbreviated number's unit.
+        unit: Option<&'a str>,
     },
 
     /// An `f64`
@@ -365,33 +1261,151 @@ enum FormattedImpl {
         /// The integer part.
         number: f64,
 
+        /// The decimal places of the unit, overriding `DECIMAL_PLACES`.
+        decimal_places: Option<u8>,
+
+        /// The abbreviated number's unit.
+        unit: Option<&'a str>,
+    },
+
+    /// A number in scientific or engineering notation.
+    Exponent {
+        /// The number.
+        number: f64,
+
+        /// Whether the exponent is a multiple of 3.
+        engineering: bool,
+
+        /// How the exponent is rendered.
+        style: ExponentStyle,
+    },
+
+    /// A number exceeding the last unit, reported by the fallible API.
+    Overflowed {
+        /// The number.
+        number: f64,
+    },
+
+    /// A number exceeding the last unit, without unit.
+    Unscaled {
+        /// The number.
+        number: f64,
+    },
+
+    /// A placeholder rendered verbatim instead of the number.
+    Placeholder {
+        /// The number, NaN if missing.
+        number: f64,
+
+        /// The placeholder.
+        placeholder: &'a str,
+    },
+
+    /// An amount of money in minor units, divided exactly.
+    Minor {
+        /// Whether the amount is positive.
+        positive: bool,
+
+        /// The amount in minor units.
+        minor_units: u128,
+
+        /// The divisor in minor units, i.e. the base of the unit times the
+        /// minor units per major unit.
+        divisor: u128,
+
+        /// The currency's fraction digits when not compacted, or the decimal
+        /// places of the unit, overriding `DECIMAL_PLACES`.
+        fraction_digits: Option<u8>,
+
+        /// The abbreviated number's unit.
+        unit: Option<&'a str>,
+    },
+
+    /// An amount of money in minor units, in scientific or engineering
+    /// notation.
+    MinorExponent {
+        /// Whether the amount is positive.
+        positive: bool,
+
+        /// The amount in minor units.
+        minor_units: u128,
+
+        /// The currency's fraction digits.
+        fraction_digits: u8,
+
+        /// Whether the exponent is a multiple of 3.
+        engineering: bool,
+
+        /// How the exponent is rendered.
+        style: ExponentStyle,
+    },
+
+    /// A number exceeding the last unit, saturated to the largest number the
+    /// last unit can show.
+    Saturated {
+        /// Whether the number is positive.
+        positive: bool,
+
+        /// The exclusive limit of the last unit, e.g. `1000` for `Y`.
+        limit: u128,
+
+        /// The decimal places of the last unit, overriding `DECIMAL_PLACES`.
+        decimal_places: Option<u8>,
+
         /// The abbreviated number's unit.
-        unit: Option<&'static str>,
+        unit: Option<&'a str>,
     },
 }
 
 #[derive(Debug)]
 /// The formatted number, with default 2 decimal places.
-pub struct Formatted<const DECIMAL_PLACES: usize = 2> {
+pub struct Formatted<'a, const DECIMAL_PLACES: usize = 2> {
     /// The formatted number.
-    number: FormattedImpl,
+    number: FormattedImpl<'a>,
 
     /// Separator between numbers and units.
     ///
     /// Defaults to be " " (space)
-    separator: &'static str,
+    separator: &'a str,
 
     /// The custom unit attached after the abbreviated number's unit.
-    custom_unit: Option<&'static str>,
+    custom_unit: Option<&'a str>,
+
+    /// The approximation and saturation markers.
+    markers: Option<Markers<'a>>,
+
+    /// How the sign is rendered.
+    sign_style: SignStyle<'a>,
+
+    /// The decimal mark and digit grouping.
+    locale: Locale<'a>,
+
+    /// The digits the number is transliterated into.
+    digit_set: DigitSet<'a>,
+
+    /// The bidi controls for right-to-left contexts.
+    bidi: Bidi,
+
+    /// The placement of prefix, suffix and units.
+    placement: Placement<'a>,
 }
 
-impl<const DECIMAL_PLACES: usize> Formatted<DECIMAL_PLACES> {
+impl<'a, const DECIMAL_PLACES: usize> Formatted<'a, DECIMAL_PLACES> {
     #[inline]
     const fn with_sign(mut self, positive: bool) -> Self {
         match &mut self.number {
-            FormattedImpl::Int { positive: p, .. } => *p = positive,
-            FormattedImpl::F64 { number, .. } => {
-                if !positive {
+            FormattedImpl::Int { positive: p, .. }
+            | FormattedImpl::Minor { positive: p, .. }
+            | FormattedImpl::MinorExponent { positive: p, .. }
+            | FormattedImpl::Saturated { positive: p, .. } => *p = positive,
+            FormattedImpl::Scaled { number, .. }
+            | FormattedImpl::F64 { number, .. }
+            | FormattedImpl::Exponent { number, .. }
+            | FormattedImpl::Overflowed { number }
+            | FormattedImpl::Unscaled { number }
+            | FormattedImpl::Placeholder { number, .. } => {
+                // NaN is unsigned, whatever its sign bit.
+                if !positive && !number.is_nan() {
                     *number = -(*number);
                 }
             }
@@ -404,6 +1418,11 @@ impl<const DECIMAL_PLACES: usize> Formatted<DECIMAL_PLACES> {
     #[must_use]
     /// Set the decimal places for the formatted number.
     ///
+    /// This takes precedence over the decimal places of the unit set with
+    /// [`Formatter::with_unit_decimal_places`] and over the fraction digits
+    /// of a currency. Integers in the unit of one are still shown without a
+    /// fraction.
+    ///
     /// ## Examples
     ///
     /// ```rust
@@ -414,7 +1433,23 @@ impl<const DECIMAL_PLACES: usize> Formatted<DECIMAL_PLACES> {
     /// assert_eq!(formatted.to_string(), "1.00 K"); // default 2 decimal places
     /// assert_eq!(formatted.with_decimal_places::<4>().to_string(), "1.0000 K"); // with 4 decimal places
     /// ```
-    pub fn with_decimal_places<const NEW_DECIMAL_PLACES: usize>(self) -> Formatted<NEW_DECIMAL_PLACES> {
+    pub fn with_decimal_places<const NEW_DECIMAL_PLACES: usize>(mut self) -> Formatted<'a, NEW_DECIMAL_PLACES> {
+        match &mut self.number {
+            FormattedImpl::Scaled { decimal_places, .. }
+            | FormattedImpl::F64 { decimal_places, .. }
+            | FormattedImpl::Saturated { decimal_places, .. }
+            | FormattedImpl::Minor {
+                fraction_digits: decimal_places,
+                ..
+            } => *decimal_places = None,
+            FormattedImpl::Int { .. }
+            | FormattedImpl::Exponent { .. }
+            | FormattedImpl::MinorExponent { .. }
+            | FormattedImpl::Overflowed { .. }
+            | FormattedImpl::Unscaled { .. }
+            | FormattedImpl::Placeholder { .. } => {}
+        }
+
         #[allow(unsafe_code, reason = "compile time const value")]
         unsafe {
             core::mem::transmute(self)
@@ -424,27 +1459,80 @@ impl<const DECIMAL_PLACES: usize> Formatted<DECIMAL_PLACES> {
     #[inline]
     #[must_use]
     /// Returns the raw number as a `f64`.
+    ///
+    /// For a saturated number, this is the largest number the last unit can
+    /// show, e.g. `999.99`.
     pub const fn number(&self) -> f64 {
         match self.number {
             FormattedImpl::Int { positive, integer, .. } => integer as f64 * if positive { 1.0 } else { -1.0 },
-            FormattedImpl::F64 { number, .. } => number,
+            FormattedImpl::Scaled { number, .. }
+            | FormattedImpl::F64 { number, .. }
+            | FormattedImpl::Exponent { number, .. }
+            | FormattedImpl::Overflowed { number }
+            | FormattedImpl::Unscaled { number }
+            | FormattedImpl::Placeholder { number, .. } => number,
+            FormattedImpl::Minor {
+                positive,
+                minor_units,
+                divisor,
+                ..
+            } => {
+                ((minor_units / divisor) as f64 + (minor_units % divisor) as f64 / divisor as f64)
+                    * if positive { 1.0 } else { -1.0 }
+            }
+            FormattedImpl::MinorExponent {
+                positive,
+                minor_units,
+                fraction_digits,
+                ..
+            } => minor_units as f64 / pow10(fraction_digits as usize) * if positive { 1.0 } else { -1.0 },
+            FormattedImpl::Saturated { positive, limit, .. } => {
+                (limit as f64 - 1.0 / pow10(self.decimal_places())) * if positive { 1.0 } else { -1.0 }
+            }
         }
     }
 
     #[inline]
     #[must_use]
     /// Returns the separator between numbers and units.
-    pub const fn separator(&self) -> &'static str {
+    pub const fn separator(&self) -> &'a str {
         self.separator
     }
 
     #[inline]
     #[must_use]
     /// Returns the custom unit attached after the abbreviated number's unit.
-    pub const fn custom_unit(&self) -> Option<&'static str> {
+    pub const fn custom_unit(&self) -> Option<&'a str> {
         self.custom_unit
     }
 
+    #[inline]
+    /// Whether the number is negative, including `-0.0` but not NaN.
+    const fn is_negative(&self) -> bool {
+        match self.number {
+            FormattedImpl::Int { positive, .. }
+            | FormattedImpl::Minor { positive, .. }
+            | FormattedImpl::MinorExponent { positive, .. }
+            | FormattedImpl::Saturated { positive, .. } => !positive,
+            FormattedImpl::Scaled { number, .. }
+            | FormattedImpl::F64 { number, .. }
+            | FormattedImpl::Exponent { number, .. }
+            | FormattedImpl::Overflowed { number }
+            | FormattedImpl::Unscaled { number }
+            | FormattedImpl::Placeholder { number, .. } => number.is_sign_negative() && !number.is_nan(),
+        }
+    }
+
+    #[inline]
+    /// Returns an error if the number exceeds the last unit and the overflow
+    /// policy is [`Overflow::Error`].
+    const fn checked(self) -> Result<Self, OverflowError> {
+        match self.number {
+            FormattedImpl::Overflowed { number } => Err(OverflowError::new(number)),
+            _ => Ok(self),
+        }
+    }
+
     #[cfg(feature = "alloc")]
     #[allow(clippy::inherent_to_string_shadow_display)]
     #[must_use]
@@ -460,99 +1548,438 @@ impl<const DECIMAL_PLACES: usize> Formatted<DECIMAL_PLACES> {
     /// assert_eq!(formatted.to_string(), "1.00 K");
     /// ```
     pub fn to_string(&self) -> String {
-        let separator = self.separator;
+        let mut result = String::with_capacity(8 + DECIMAL_PLACES);
+
+        // Writing to a `String` never fails.
+        let _ = self.write_to(&mut result);
+
+        result
+    }
+
+    /// Writes the formatted number to the given writer.
+    fn write_to<W: Write>(&self, w: &mut W) -> fmt::Result {
+        let Some(isolate) = self.bidi.isolate() else {
+            return self.write_isolated(w);
+        };
+
+        w.write_char(isolate)?;
+        self.write_isolated(w)?;
+        w.write_char(Bidi::POP_ISOLATE)
+    }
+
+    /// Writes the formatted number, without the bidi isolate.
+    fn write_isolated<W: Write>(&self, w: &mut W) -> fmt::Result {
+        if let FormattedImpl::Placeholder { placeholder, .. } = self.number {
+            return w.write_str(placeholder);
+        }
+
+        if let Some(marker) = self.marker() {
+            w.write_str(marker)?;
+        }
+
+        let negative = self.is_negative();
+
+        let sign = if negative {
+            self.sign_style.negative
+        } else {
+            self.sign_style.positive
+        };
+
+        if self.digit_set.is_ascii() {
+            w.write_str(sign)?;
+        } else {
+            digit::Transliterate::new(w, &self.digit_set).write_str(sign)?;
+        }
+
+        w.write_str(self.placement.prefix)?;
+
+        let unit = self.unit();
+
+        if self.placement.unit_position == UnitPosition::Before {
+            self.write_unit(w, unit)?;
+        }
+
+        if self.digit_set.is_ascii() {
+            self.write_magnitude(w)?;
+        } else {
+            self.write_magnitude(&mut digit::Transliterate::new(w, &self.digit_set))?;
+        }
+
+        if self.placement.unit_position == UnitPosition::After {
+            self.write_unit(w, unit)?;
+        }
+
+        w.write_str(self.placement.suffix)?;
+
+        if negative {
+            w.write_str(self.sign_style.negative_suffix)?;
+        }
+
+        Ok(())
+    }
 
+    #[inline]
+    /// Returns the abbreviated number's unit, if any.
+    const fn unit(&self) -> Option<&'a str> {
         match self.number {
-            FormattedImpl::Int {
-                positive,
-                integer,
-                unit,
-            } => {
-                let sign = if positive { "" } else { "-" };
+            FormattedImpl::Int { unit, .. }
+            | FormattedImpl::Scaled { unit, .. }
+            | FormattedImpl::F64 { unit, .. }
+            | FormattedImpl::Minor { unit, .. }
+            | FormattedImpl::Saturated { unit, .. } => unit,
+            FormattedImpl::Exponent { .. }
+            | FormattedImpl::MinorExponent { .. }
+            | FormattedImpl::Overflowed { .. }
+            | FormattedImpl::Unscaled { .. }
+            | FormattedImpl::Placeholder { .. } => None,
+        }
+    }
 
-                // TODO: fast number formatting?
+    #[inline]
+    /// Returns the decimal places of the number's unit, `DECIMAL_PLACES` if not
+    /// overridden.
+    const fn decimal_places(&self) -> usize {
+        match self.number {
+            FormattedImpl::Scaled { decimal_places, .. }
+            | FormattedImpl::F64 { decimal_places, .. }
+            | FormattedImpl::Saturated { decimal_places, .. }
+            | FormattedImpl::Minor {
+                fraction_digits: decimal_places,
+                ..
+            } => match decimal_places {
+                Some(decimal_places) => decimal_places as usize,
+                None => DECIMAL_PLACES,
+            },
+            FormattedImpl::Int { .. }
+            | FormattedImpl::Exponent { .. }
+            | FormattedImpl::MinorExponent { .. }
+            | FormattedImpl::Overflowed { .. }
+            | FormattedImpl::Unscaled { .. }
+            | FormattedImpl::Placeholder { .. } => DECIMAL_PLACES,
+        }
+    }
 
-                match (unit, self.custom_unit) {
-                    (Some(unit), Some(custom_unit)) => format!("{sign}{integer}{separator}{unit}{custom_unit}"),
-                    (Some(unit), None) => format!("{sign}{integer}{separator}{unit}"),
-                    (None, Some(custom_unit)) => format!("{sign}{integer}{separator}{custom_unit}"),
-                    (None, None) => format!("{sign}{integer}"),
-                }
+    #[inline]
+    /// Returns the locale, with its `.` decimal mark replaced by the one of
+    /// the digit set. Group separators are kept as is.
+    fn locale(&self) -> Locale<'a> {
+        if self.locale.decimal_mark == "." {
+            Locale {
+                decimal_mark: self.digit_set.decimal_mark,
+                ..self.locale
             }
-            FormattedImpl::F64 { number, unit } => {
-                let mut result = String::with_capacity(8 + DECIMAL_PLACES);
+        } else {
+            self.locale
+        }
+    }
 
-                let mut formatted = ryuu::Formatter::format_f64(number);
+    /// Writes the magnitude of the number.
+    fn write_magnitude<W: Write>(&self, w: &mut W) -> fmt::Result {
+        let locale = self.locale();
 
-                let formatted = formatted.as_str_adjusting_dp::<DECIMAL_PLACES>();
+        match self.number {
+            FormattedImpl::Int { integer, .. } => {
+                locale.write_u128(w, integer)?;
 
-                match (unit, self.custom_unit) {
-                    (Some(unit), Some(custom_unit)) => {
-                        result.push_str(formatted);
-                        result.push_str(separator);
-                        result.push_str(unit);
-                        result.push_str(custom_unit);
-                    }
-                    (Some(unit), None) => {
-                        result.push_str(formatted);
-                        result.push_str(separator);
-                        result.push_str(unit);
+                Ok(())
+            }
+            FormattedImpl::Scaled { number, .. } | FormattedImpl::F64 { number, .. } => {
+                let decimal_places = self.decimal_places();
+
+                if self.markers.is_some() && is_below(number, decimal_places) {
+                    // The smallest non-zero number that can be shown, e.g. `0.01`.
+                    if decimal_places == 0 {
+                        w.write_char('1')?;
+                    } else {
+                        w.write_char('0')?;
+                        w.write_str(locale.decimal_mark)?;
+
+                        for _ in 1..decimal_places {
+                            w.write_char('0')?;
+                        }
+
+                        w.write_char('1')?;
                     }
-                    (None, Some(custom_unit)) => {
-                        result.push_str(formatted);
-                        result.push_str(separator);
-                        result.push_str(custom_unit);
+                } else {
+                    // Tiny numbers, e.g. in a pinned unit, are shown as zero instead of in
+                    // scientific notation.
+                    let number = if is_below(number, decimal_places) {
+                        0.0
+                    } else {
+                        magnitude(number)
+                    };
+
+                    locale.write_fixed(w, ryuu::Formatter::format_f64(number).as_str(), decimal_places)?;
+                }
+
+                Ok(())
+            }
+            FormattedImpl::Exponent {
+                number,
+                engineering,
+                style,
+            } => {
+                notation::write_exponent::<DECIMAL_PLACES, _>(
+                    w,
+                    magnitude(number),
+                    engineering,
+                    style,
+                    locale.decimal_mark,
+                )?;
+
+                Ok(())
+            }
+            FormattedImpl::MinorExponent {
+                minor_units,
+                fraction_digits,
+                engineering,
+                style,
+                ..
+            } => notation::write_exponent_exact::<DECIMAL_PLACES, _>(
+                w,
+                minor_units,
+                fraction_digits,
+                engineering,
+                style,
+                locale.decimal_mark,
+            ),
+            // As is, e.g. `1e33`, without unit to be read with.
+            FormattedImpl::Overflowed { number } | FormattedImpl::Unscaled { number } => {
+                let mut formatted = ryuu::Formatter::format_f64(magnitude(number));
+
+                locale.write_number(w, formatted.as_str_adjusting_dp::<DECIMAL_PLACES>())?;
+
+                Ok(())
+            }
+            FormattedImpl::Placeholder { .. } => Ok(()),
+            FormattedImpl::Minor {
+                minor_units, divisor, ..
+            } => {
+                locale.write_u128(w, minor_units / divisor)?;
+
+                let fraction_digits = self.decimal_places();
+
+                if fraction_digits > 0 {
+                    w.write_str(locale.decimal_mark)?;
+
+                    let mut remainder = minor_units % divisor;
+
+                    for _ in 0..fraction_digits {
+                        remainder *= 10;
+                        w.write_char(char::from(b'0' + u8::try_from(remainder / divisor).unwrap_or(9)))?;
+                        remainder %= divisor;
                     }
-                    (None, None) => {
-                        result.push_str(formatted);
+                }
+
+                Ok(())
+            }
+            FormattedImpl::Saturated { limit, .. } => {
+                locale.write_u128(w, limit.saturating_sub(1))?;
+
+                let decimal_places = self.decimal_places();
+
+                if decimal_places > 0 {
+                    w.write_str(locale.decimal_mark)?;
+
+                    for _ in 0..decimal_places {
+                        w.write_char('9')?;
                     }
                 }
 
-                result
+                Ok(())
             }
         }
     }
-}
 
-#[cfg(not(feature = "alloc"))]
-impl<const DECIMAL_PLACES: usize> fmt::Display for Formatted<DECIMAL_PLACES> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let separator = self.separator;
+    /// Writes the abbreviated number's unit and the custom unit with the
+    /// separator on the side of the number, if any, between the bidi marks.
+    fn write_unit<W: Write>(&self, w: &mut W, unit: Option<&'a str>) -> fmt::Result {
+        if unit.is_none() && self.custom_unit.is_none() {
+            return Ok(());
+        }
+
+        let mark = self.bidi.mark();
+
+        if let Some(mark) = mark {
+            w.write_char(mark)?;
+        }
+
+        if self.placement.unit_position == UnitPosition::After {
+            w.write_str(self.separator)?;
+        }
+
+        if let Some(unit) = unit {
+            w.write_str(unit)?;
+        }
+
+        if let Some(custom_unit) = self.custom_unit {
+            w.write_str(custom_unit)?;
+        }
+
+        if self.placement.unit_position == UnitPosition::Before {
+            w.write_str(self.separator)?;
+        }
+
+        if let Some(mark) = mark {
+            w.write_char(mark)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the marker to be prepended, if any.
+    fn marker(&self) -> Option<&'a str> {
+        let markers = self.markers.as_ref()?;
 
         match self.number {
-            FormattedImpl::Int {
-                positive,
-                integer,
-                unit,
+            FormattedImpl::Int { .. } | FormattedImpl::Placeholder { .. } => None,
+            FormattedImpl::Scaled {
+                number,
+                remainder,
+                divisor,
+                ..
             } => {
-                let sign = if positive { "" } else { "-" };
-
-                match (unit, self.custom_unit) {
-                    (Some(unit), Some(custom_unit)) => write!(f, "{sign}{integer}{separator}{unit}{custom_unit}"),
-                    (Some(unit), None) => write!(f, "{sign}{integer}{separator}{unit}"),
-                    (None, Some(custom_unit)) => write!(f, "{sign}{integer}{separator}{custom_unit}"),
-                    (None, None) => write!(f, "{sign}{integer}"),
+                if is_below(number, self.decimal_places()) {
+                    // Rounds to zero in a clamped or pinned unit, e.g. `<0.01 Ki`.
+                    Some(if number < 0.0 {
+                        markers.greater_than
+                    } else {
+                        markers.less_than
+                    })
+                } else {
+                    (!is_exact(remainder, divisor, self.decimal_places())).then_some(markers.approximate)
                 }
             }
-            FormattedImpl::F64 { number, unit } => {
-                let mut formatted = ryuu::Formatter::format_f64(number);
-
-                let formatted = formatted.as_str_adjusting_dp::<DECIMAL_PLACES>();
-
-                match (unit, self.custom_unit) {
-                    (Some(unit), Some(custom_unit)) => write!(f, "{formatted}{separator}{unit}{custom_unit}"),
-                    (Some(unit), None) => write!(f, "{formatted}{separator}{unit}"),
-                    (None, Some(custom_unit)) => write!(f, "{formatted}{separator}{custom_unit}"),
-                    (None, None) => write!(f, "{formatted}"),
+            FormattedImpl::Minor {
+                minor_units, divisor, ..
+            } => (!is_exact(minor_units % divisor, divisor, self.decimal_places())).then_some(markers.approximate),
+            FormattedImpl::F64 { number, .. } => {
+                if !number.is_finite() {
+                    None
+                } else if is_below(number, self.decimal_places()) {
+                    Some(if number < 0.0 {
+                        markers.greater_than
+                    } else {
+                        markers.less_than
+                    })
+                } else {
+                    is_truncated(number, self.decimal_places()).then_some(markers.approximate)
                 }
             }
+            FormattedImpl::Exponent {
+                number, engineering, ..
+            } => notation::is_exponent_truncated::<DECIMAL_PLACES>(number, engineering).then_some(markers.approximate),
+            FormattedImpl::MinorExponent {
+                minor_units,
+                fraction_digits,
+                engineering,
+                ..
+            } => notation::is_exponent_exact_truncated::<DECIMAL_PLACES>(minor_units, fraction_digits, engineering)
+                .then_some(markers.approximate),
+            FormattedImpl::Overflowed { number } | FormattedImpl::Unscaled { number } => {
+                is_truncated(number, DECIMAL_PLACES).then_some(markers.approximate)
+            }
+            FormattedImpl::Saturated { positive, .. } => Some(if positive {
+                markers.greater_than
+            } else {
+                markers.less_than
+            }),
         }
     }
 }
 
-#[cfg(feature = "alloc")]
-impl<const DECIMAL_PLACES: usize> fmt::Display for Formatted<DECIMAL_PLACES> {
+impl<const DECIMAL_PLACES: usize> fmt::Display for Formatted<'_, DECIMAL_PLACES> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.to_string().as_str())
+        self.write_to(f)
     }
 }
+
+/// Returns the magnitude of `number`, i.e. `number` without sign.
+const fn magnitude(number: f64) -> f64 {
+    if number.is_sign_negative() {
+        -number
+    } else {
+        number
+    }
+}
+
+/// Returns `10^exp` as an `f64`.
+const fn pow10(exp: usize) -> f64 {
+    let mut result = 1.0;
+
+    const_for!(_ in 0..exp => {
+        result *= 10.0;
+    });
+
+    result
+}
+
+/// Rounds a large non-negative quotient to the 15 significant digits an
+/// `f64` holds, so that division artifacts like `999999999999999900000` are
+/// shown as `1000000000000000000000`. Smaller and larger numbers are kept.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn round_significant(number: f64) -> f64 {
+    if !(number >= 1e15 && number < 1e38) {
+        return number;
+    }
+
+    let integer = number as u128;
+    let mut unit = 1;
+
+    while integer / unit >= 1_000_000_000_000_000 {
+        unit *= 10;
+    }
+
+    ((integer + unit / 2) / unit * unit) as f64
+}
+
+/// Whether a non-zero `number` rounds to zero with `decimal_places`.
+const fn is_below(number: f64, decimal_places: usize) -> bool {
+    let magnitude = magnitude(number);
+
+    magnitude != 0.0 && magnitude * pow10(decimal_places) < 1.0
+}
+
+/// Whether the shortest representation of `number` has non-zero digits
+/// beyond `decimal_places`.
+#[allow(clippy::cast_possible_wrap)]
+fn is_truncated(number: f64, decimal_places: usize) -> bool {
+    let formatted = ryuu::Formatter::format_finite_f64(number);
+    let (mantissa, exponent) = locale::split_exponent(formatted.as_str());
+    let fractional = mantissa.split_once('.').map_or("", |(_, fractional)| fractional);
+
+    // The number of significant fraction digits, once the exponent is expanded.
+    fractional.trim_end_matches('0').len() as isize - exponent > decimal_places as isize
+}
+
+/// Whether `remainder / divisor` can be shown exactly with `decimal_places`.
+///
+/// This is the case when the reduced denominator is `2^a * 5^b`, with both `a`
+/// and `b` not greater than `decimal_places`.
+const fn is_exact(remainder: u128, divisor: u128, decimal_places: usize) -> bool {
+    if remainder == 0 {
+        return true;
+    }
+
+    let (mut a, mut b) = (remainder, divisor);
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    let mut denominator = divisor / a;
+    let mut twos = 0;
+    let mut fives = 0;
+
+    while denominator % 2 == 0 {
+        denominator /= 2;
+        twos += 1;
+    }
+
+    while denominator % 5 == 0 {
+        denominator /= 5;
+        fives += 1;
+    }
+
+    denominator == 1 && twos <= decimal_places && fives <= decimal_places
+}
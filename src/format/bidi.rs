@@ -0,0 +1,95 @@
+//! Bidirectional text support.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Unicode bidi controls keeping the number and the units in order when
+/// embedded in right-to-left text.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::Bidi;
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::BINARY.with_custom_unit("B");
+///
+/// assert_eq!(
+///     formatter.with_bidi(Bidi::Isolate).format(1_536).to_string(),
+///     "\u{2068}1.50 KiB\u{2069}"
+/// );
+/// assert_eq!(
+///     formatter
+///         .with_bidi(Bidi::LeftToRightMark)
+///         .format(1_536)
+///         .to_string(),
+///     "1.50\u{200e} KiB\u{200e}"
+/// );
+/// ```
+///
+/// With right-to-left units read before the number:
+///
+/// ```rust
+/// use humat::format::{Bidi, Placement};
+/// use humat::Formatter;
+///
+/// let formatter = humat::ladder!(base = 1_000, units = [_, "אלף"])
+///     .with_placement(Placement::UNITS_BEFORE)
+///     .with_bidi(Bidi::RightToLeftMark);
+///
+/// assert_eq!(
+///     formatter.format(1_200).to_string(),
+///     "\u{200f}אלף \u{200f}1.20"
+/// );
+/// ```
+pub enum Bidi {
+    #[default]
+    /// No bidi controls.
+    Off,
+
+    /// Wraps the output in first strong isolate (U+2068) and pop directional
+    /// isolate (U+2069), so it does not affect the surrounding text.
+    Isolate,
+
+    /// Wraps the output in left-to-right isolate (U+2066) and pop directional
+    /// isolate (U+2069), keeping the number before the units even in
+    /// right-to-left text.
+    LeftToRightIsolate,
+
+    /// Wraps the output in right-to-left isolate (U+2067) and pop directional
+    /// isolate (U+2069), placing the units before the number visually, e.g.
+    /// for Arabic or Hebrew units.
+    RightToLeftIsolate,
+
+    /// Puts left-to-right marks (U+200E) around the units and their
+    /// separator, for contexts not supporting isolates.
+    LeftToRightMark,
+
+    /// Puts right-to-left marks (U+200F) around the units and their
+    /// separator, for contexts not supporting isolates.
+    RightToLeftMark,
+}
+
+impl Bidi {
+    /// Pop directional isolate, closing an isolate.
+    pub(super) const POP_ISOLATE: char = '\u{2069}';
+
+    #[inline]
+    /// Returns the isolate the output is wrapped in, if any.
+    pub(super) const fn isolate(self) -> Option<char> {
+        match self {
+            Self::Isolate => Some('\u{2068}'),
+            Self::LeftToRightIsolate => Some('\u{2066}'),
+            Self::RightToLeftIsolate => Some('\u{2067}'),
+            Self::Off | Self::LeftToRightMark | Self::RightToLeftMark => None,
+        }
+    }
+
+    #[inline]
+    /// Returns the mark put around the units and their separator, if any.
+    pub(super) const fn mark(self) -> Option<char> {
+        match self {
+            Self::LeftToRightMark => Some('\u{200e}'),
+            Self::RightToLeftMark => Some('\u{200f}'),
+            Self::Off | Self::Isolate | Self::LeftToRightIsolate | Self::RightToLeftIsolate => None,
+        }
+    }
+}
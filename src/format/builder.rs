@@ -0,0 +1,379 @@
+//! Runtime builder of formatters from owned units.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::num::NonZeroU128;
+
+use super::{
+    Bidi, DigitSet, ExponentStyle, Formatter, Locale, Markers, Notation, Overflow, Placeholders, Placement, SignStyle,
+};
+use crate::unit::{LadderError, Magnitude, RangedUnit};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// How the `range_max` of the ranged units are given.
+enum Thresholds {
+    /// The nth `range_max` is the nth power of the base.
+    Base(u128),
+
+    /// The `range_max` of each ranged unit.
+    Explicit(Vec<u128>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Builder of a formatter from owned units, e.g. loaded from a configuration
+/// at runtime.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::{FormatterBuilder, FormatterOptions, Markers};
+///
+/// let owned = FormatterBuilder::from_base(1_000)
+///     .with_units(["", "k", "M", "G"].map(String::from).to_vec())
+///     .with_separator(String::new())
+///     .with_custom_unit(String::from("m"))
+///     .with_options(FormatterOptions {
+///         markers: Some(Markers::DEFAULT),
+///         ..FormatterOptions::DEFAULT
+///     })
+///     .build()
+///     .unwrap();
+///
+/// let formatter = owned.formatter();
+///
+/// assert_eq!(formatter.format(12).to_string(), "12m");
+/// assert_eq!(formatter.format(1_500).to_string(), "1.50km");
+/// assert_eq!(formatter.format(1_234_567).to_string(), "~1.23Mm");
+/// ```
+pub struct FormatterBuilder {
+    /// The `range_max` of the ranged units.
+    thresholds: Thresholds,
+
+    /// The units of the ranged units, empty for no unit.
+    units: Vec<String>,
+
+    /// Separator between numbers and units.
+    separator: String,
+
+    /// The custom unit attached after the abbreviated number's unit.
+    custom_unit: Option<String>,
+
+    /// The other options, e.g. markers, overflow or locale.
+    options: FormatterOptions<'static>,
+}
+
+impl FormatterBuilder {
+    #[inline]
+    #[must_use]
+    /// Creates a builder where the nth `range_max` is the nth power of `base`.
+    pub fn from_base(base: u128) -> Self {
+        Self::new(Thresholds::Base(base))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Creates a builder with the `range_max` of each ranged unit.
+    ///
+    /// The thresholds are validated like [`Formatter::custom`], i.e. the nth
+    /// threshold must be the nth power of the first one.
+    pub fn from_thresholds(thresholds: Vec<u128>) -> Self {
+        Self::new(Thresholds::Explicit(thresholds))
+    }
+
+    #[inline]
+    /// Creates a builder with the default separator " " (space), no units and
+    /// the default options.
+    fn new(thresholds: Thresholds) -> Self {
+        Self {
+            thresholds,
+            units: Vec::new(),
+            separator: String::from(" "),
+            custom_unit: None,
+            options: FormatterOptions::DEFAULT,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the units of the ranged units, in ascending order.
+    ///
+    /// An empty unit means no unit, which is usual for the first one.
+    pub fn with_units(self, units: Vec<String>) -> Self {
+        Self { units, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the separator between numbers and units.
+    pub fn with_separator(self, separator: String) -> Self {
+        Self { separator, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set custom unit attached after the abbreviated number's unit.
+    pub fn with_custom_unit(self, custom_unit: String) -> Self {
+        Self {
+            custom_unit: Some(custom_unit),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the other options, e.g. markers, overflow or locale.
+    ///
+    /// The options of the units, e.g. [`Formatter::with_min_unit`], refer to
+    /// the built units, and options borrowing runtime strings outlive the
+    /// builder, so they are set on [`OwnedFormatter::formatter`].
+    pub fn with_options(self, options: FormatterOptions<'static>) -> Self {
+        Self { options, ..self }
+    }
+
+    /// Validates the units into an [`OwnedFormatter`].
+    ///
+    /// ## Errors
+    ///
+    /// See [`BuildError`].
+    pub fn build(self) -> Result<OwnedFormatter, BuildError> {
+        let range_maxes = match &self.thresholds {
+            Thresholds::Base(base) => {
+                let base = NonZeroU128::new(*base).ok_or(BuildError::Ladder(LadderError::ZeroBase))?;
+
+                (1..=self.units.len())
+                    .map(|exponent| {
+                        #[allow(clippy::cast_possible_truncation)]
+                        Magnitude::new(base, exponent as u32)
+                    })
+                    .collect()
+            }
+            Thresholds::Explicit(thresholds) => {
+                if thresholds.len() != self.units.len() {
+                    return Err(BuildError::LengthMismatch {
+                        thresholds: thresholds.len(),
+                        units: self.units.len(),
+                    });
+                }
+
+                thresholds
+                    .iter()
+                    .enumerate()
+                    .map(|(index, &threshold)| {
+                        NonZeroU128::new(threshold)
+                            .map(|threshold| Magnitude::new(threshold, 1))
+                            .ok_or(BuildError::Ladder(if index == 0 {
+                                LadderError::ZeroBase
+                            } else {
+                                LadderError::NonMonotonic { index }
+                            }))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+        };
+
+        let owned = OwnedFormatter {
+            ranged_units: OwnedFormatter::ranged_units(range_maxes, &self.units),
+            units: self.units,
+            separator: self.separator,
+            custom_unit: self.custom_unit,
+            options: self.options,
+        };
+
+        if let Err(error) = Formatter::custom(&owned.ranged_units) {
+            return Err(BuildError::Ladder(error));
+        }
+
+        Ok(owned)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The options of a formatter besides its units, separator and custom unit,
+/// see the `with_*` methods of [`Formatter`].
+pub struct FormatterOptions<'a> {
+    /// The approximation and saturation markers, disabled by default.
+    pub markers: Option<Markers<'a>>,
+
+    /// What to do with a number exceeding the last unit.
+    pub overflow: Overflow,
+
+    /// How numbers are rendered.
+    pub notation: Notation,
+
+    /// How the exponent of scientific or engineering notation is rendered.
+    pub exponent_style: ExponentStyle,
+
+    /// Placeholders for non-finite, negative zero and missing numbers.
+    pub placeholders: Placeholders<'a>,
+
+    /// How the sign is rendered.
+    pub sign_style: SignStyle<'a>,
+
+    /// The decimal mark and digit grouping.
+    pub locale: Locale<'a>,
+
+    /// The digits the number is transliterated into.
+    pub digit_set: DigitSet<'a>,
+
+    /// The bidi controls for right-to-left contexts.
+    pub bidi: Bidi,
+
+    /// The placement of prefix, suffix and units.
+    pub placement: Placement<'a>,
+}
+
+impl FormatterOptions<'_> {
+    /// The default options, as of [`Formatter::custom`].
+    pub const DEFAULT: Self = Self {
+        markers: None,
+        overflow: Overflow::Unscaled,
+        notation: Notation::Units,
+        exponent_style: ExponentStyle::Lowercase,
+        placeholders: Placeholders::DEFAULT,
+        sign_style: SignStyle::HYPHEN,
+        locale: Locale::DEFAULT,
+        digit_set: DigitSet::ASCII,
+        bidi: Bidi::Off,
+        placement: Placement::DEFAULT,
+    };
+}
+
+impl Default for FormatterOptions<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Debug)]
+/// A formatter owning its units, built by a [`FormatterBuilder`], e.g. to be
+/// stored per tenant.
+///
+/// The formatter is handed out on demand by [`OwnedFormatter::formatter`].
+pub struct OwnedFormatter {
+    /// The ranged units, whose units borrow `units` rather than being
+    /// `'static`.
+    ranged_units: Vec<RangedUnit<'static>>,
+
+    /// The units of the ranged units, never modified.
+    units: Vec<String>,
+
+    /// Separator between numbers and units.
+    separator: String,
+
+    /// The custom unit attached after the abbreviated number's unit.
+    custom_unit: Option<String>,
+
+    /// The other options.
+    options: FormatterOptions<'static>,
+}
+
+impl OwnedFormatter {
+    /// Creates the ranged units borrowing `units`, where an empty unit means
+    /// no unit.
+    fn ranged_units(range_maxes: Vec<Magnitude>, units: &[String]) -> Vec<RangedUnit<'static>> {
+        range_maxes
+            .into_iter()
+            .zip(units)
+            .map(|(range_max, unit)| RangedUnit {
+                range_max,
+                unit: (!unit.is_empty()).then(|| {
+                    #[allow(unsafe_code, reason = "The units are owned alongside and never modified.")]
+                    // SAFETY: The unit is on the heap, so it stays in place when `units` is
+                    // moved into `Self`, and lives as long as `Self` as it is never modified.
+                    // It is only handed out for a borrow of `Self`, by `Self::formatter`.
+                    unsafe {
+                        &*core::ptr::from_ref::<str>(unit)
+                    }
+                }),
+            })
+            .collect()
+    }
+
+    #[must_use]
+    /// Returns a formatter with the owned units and the options of the
+    /// builder, for further options like [`Formatter::with_min_unit`].
+    pub fn formatter(&self) -> Formatter<'_> {
+        let ranged_units: &[RangedUnit<'_>] = &self.ranged_units;
+        let options = self.options;
+
+        let formatter = Formatter::preset(ranged_units, options.locale)
+            .with_separator(&self.separator)
+            .with_overflow(options.overflow)
+            .with_notation(options.notation)
+            .with_exponent_style(options.exponent_style)
+            .with_placeholders(options.placeholders)
+            .with_sign_style(options.sign_style)
+            .with_digit_set(options.digit_set)
+            .with_bidi(options.bidi)
+            .with_placement(options.placement);
+
+        let formatter = match options.markers {
+            Some(markers) => formatter.with_markers(markers),
+            None => formatter,
+        };
+
+        match &self.custom_unit {
+            Some(custom_unit) => formatter.with_custom_unit(custom_unit),
+            None => formatter,
+        }
+    }
+}
+
+impl Clone for OwnedFormatter {
+    fn clone(&self) -> Self {
+        let units = self.units.clone();
+
+        Self {
+            // The ranged units of the clone borrow its own units.
+            ranged_units: Self::ranged_units(
+                self.ranged_units
+                    .iter()
+                    .map(|ranged_unit| ranged_unit.range_max)
+                    .collect(),
+                &units,
+            ),
+            units,
+            separator: self.separator.clone(),
+            custom_unit: self.custom_unit.clone(),
+            options: self.options,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Error returned by [`FormatterBuilder::build`].
+pub enum BuildError {
+    /// The number of thresholds does not match the number of units.
+    LengthMismatch {
+        /// The number of thresholds.
+        thresholds: usize,
+
+        /// The number of units.
+        units: usize,
+    },
+
+    /// The ranged units are invalid, see [`Formatter::custom`].
+    Ladder(LadderError),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { thresholds, units } => {
+                write!(f, "got {thresholds} thresholds for {units} units")
+            }
+            Self::Ladder(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Ladder(error) => Some(error),
+            Self::LengthMismatch { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! CLDR compact decimal presets, e.g. `1.2K`, `1,2 Mio.` or `1.2万`.
+//!
+//! The units are an embedded subset of the CLDR compact decimal formats, short
+//! and long, using the `other` plural form. Numbers are shown with fixed
+//! decimal places rather than CLDR's significant digits, and numbers beyond
+//! the last unit keep it, e.g. `1000.00T`, like CLDR does.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use humat::format::cldr;
+//!
+//! assert_eq!(cldr::EN_SHORT.format(1_234).to_string(), "1.23K");
+//! assert_eq!(cldr::EN_LONG.format(1_234).to_string(), "1.23 thousand");
+//! assert_eq!(cldr::JA_SHORT.format(12_345).to_string(), "1.23万");
+//! assert_eq!(cldr::DE_SHORT.format(1_234).to_string(), "1234");
+//! assert_eq!(
+//!     cldr::DE_SHORT.format(1_234_567).to_string(),
+//!     "1,23\u{a0}Mio."
+//! );
+//! ```
+
+use core::num::NonZeroU128;
+
+use super::{Formatter, Locale, Overflow};
+use crate::unit::{Magnitude, RangedUnit};
+
+const THOUSAND: NonZeroU128 = NonZeroU128::new(1_000).unwrap();
+
+const TEN_THOUSAND: NonZeroU128 = NonZeroU128::new(10_000).unwrap();
+
+static EN_SHORT_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(THOUSAND, [None, Some("K"), Some("M"), Some("B"), Some("T")]);
+
+static EN_LONG_UNITS: [RangedUnit; 5] = RangedUnit::geometric(
+    THOUSAND,
+    [
+        None,
+        Some("thousand"),
+        Some("million"),
+        Some("billion"),
+        Some("trillion"),
+    ],
+);
+
+// Not a geometric ladder, as thousands are not abbreviated: the first unit
+// ends at a million, then each unit spans a thousand. The units are divided by
+// the previous `range_max`, so the preset is built without the checks of
+// `Formatter::custom`, intentionally.
+static DE_SHORT_UNITS: [RangedUnit; 4] = [
+    RangedUnit {
+        range_max: Magnitude::new(THOUSAND, 2),
+        unit: None,
+    },
+    RangedUnit {
+        range_max: Magnitude::new(THOUSAND, 3),
+        unit: Some("Mio."),
+    },
+    RangedUnit {
+        range_max: Magnitude::new(THOUSAND, 4),
+        unit: Some("Mrd."),
+    },
+    RangedUnit {
+        range_max: Magnitude::new(THOUSAND, 5),
+        unit: Some("Bio."),
+    },
+];
+
+static DE_LONG_UNITS: [RangedUnit; 5] = RangedUnit::geometric(
+    THOUSAND,
+    [
+        None,
+        Some("Tausend"),
+        Some("Millionen"),
+        Some("Milliarden"),
+        Some("Billionen"),
+    ],
+);
+
+static FR_SHORT_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(THOUSAND, [None, Some("k"), Some("M"), Some("Md"), Some("Bn")]);
+
+static FR_LONG_UNITS: [RangedUnit; 5] = RangedUnit::geometric(
+    THOUSAND,
+    [
+        None,
+        Some("mille"),
+        Some("millions"),
+        Some("milliards"),
+        Some("billions"),
+    ],
+);
+
+static JA_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(TEN_THOUSAND, [None, Some("万"), Some("億"), Some("兆"), Some("京")]);
+
+static ZH_UNITS: [RangedUnit; 4] = RangedUnit::geometric(TEN_THOUSAND, [None, Some("万"), Some("亿"), Some("万亿")]);
+
+/// Creates a CLDR compact decimal preset, without the checks of
+/// [`Formatter::custom`].
+const fn compact(
+    ranged_units: &'static [RangedUnit<'static>],
+    separator: &'static str,
+    locale: Locale<'static>,
+) -> Formatter<'static> {
+    Formatter::preset(
+        ranged_units,
+        Locale {
+            // Compact numbers are grouped from 5 digits on, e.g. `1234` and `12,345`.
+            min_grouping_digits: 2,
+            ..locale
+        },
+    )
+    .with_separator(separator)
+    .with_overflow(Overflow::Extend)
+}
+
+/// English short (`K`, `M`, `B`, `T`)
+pub const EN_SHORT: Formatter<'static> = compact(&EN_SHORT_UNITS, "", Locale::EN);
+
+/// English long (`thousand`, `million`, `billion`, `trillion`)
+pub const EN_LONG: Formatter<'static> = compact(&EN_LONG_UNITS, " ", Locale::EN);
+
+/// German short (`Mio.`, `Mrd.`, `Bio.`), thousands are not abbreviated
+///
+/// The units are not a geometric ladder, i.e. [`Formatter::custom`] rejects
+/// them, and each unit is divided by the `range_max` of the previous one.
+pub const DE_SHORT: Formatter<'static> = compact(&DE_SHORT_UNITS, "\u{a0}", Locale::DE);
+
+/// German long (`Tausend`, `Millionen`, `Milliarden`, `Billionen`)
+pub const DE_LONG: Formatter<'static> = compact(&DE_LONG_UNITS, " ", Locale::DE);
+
+/// French short (`k`, `M`, `Md`, `Bn`)
+pub const FR_SHORT: Formatter<'static> = compact(&FR_SHORT_UNITS, "\u{a0}", Locale::FR);
+
+/// French long (`mille`, `millions`, `milliards`, `billions`)
+pub const FR_LONG: Formatter<'static> = compact(&FR_LONG_UNITS, " ", Locale::FR);
+
+/// Japanese short (`万`, `億`, `兆`, `京`)
+pub const JA_SHORT: Formatter<'static> = compact(&JA_UNITS, "", Locale::EN);
+
+/// Japanese long, the same as [`JA_SHORT`]
+pub const JA_LONG: Formatter<'static> = compact(&JA_UNITS, "", Locale::EN);
+
+/// Chinese short (`万`, `亿`, `万亿`)
+pub const ZH_SHORT: Formatter<'static> = compact(&ZH_UNITS, "", Locale::EN);
+
+/// Chinese long, the same as [`ZH_SHORT`]
+pub const ZH_LONG: Formatter<'static> = compact(&ZH_UNITS, "", Locale::EN);
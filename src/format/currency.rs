@@ -0,0 +1,153 @@
+//! ISO 4217 currencies.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// An ISO 4217 currency, for formatting money in minor units.
+///
+/// See [`Formatter::format_money`](super::Formatter::format_money).
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::Currency;
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI.with_separator("");
+/// assert_eq!(
+///     formatter.format_money(12_345, Currency::USD).to_string(),
+///     "$123.45"
+/// );
+/// assert_eq!(
+///     formatter
+///         .format_money(120_000_000, Currency::USD)
+///         .to_string(),
+///     "$1.20M"
+/// );
+/// assert_eq!(
+///     formatter.format_money(-999, Currency::JPY).to_string(),
+///     "-¥999"
+/// );
+///
+/// assert_eq!(Currency::from_code("EUR"), Some(Currency::EUR));
+/// ```
+pub struct Currency {
+    /// The ISO 4217 alphabetic code, e.g. `USD`.
+    pub code: &'static str,
+
+    /// The symbol, e.g. `$`.
+    pub symbol: &'static str,
+
+    /// The number of minor unit digits, e.g. `2` for cents.
+    ///
+    /// ISO 4217 uses at most 4. Beyond 37, amounts are formatted through
+    /// `f64`.
+    pub fraction_digits: u8,
+
+    /// The amount in major units from which money is compacted, the first
+    /// scaled unit if `None`.
+    pub compact_threshold: Option<u128>,
+}
+
+impl Currency {
+    /// UAE dirham
+    pub const AED: Self = Self::new("AED", "AED\u{a0}", 2);
+    /// Australian dollar
+    pub const AUD: Self = Self::new("AUD", "A$", 2);
+    /// Bahraini dinar
+    pub const BHD: Self = Self::new("BHD", "BHD\u{a0}", 3);
+    /// Brazilian real
+    pub const BRL: Self = Self::new("BRL", "R$", 2);
+    /// Canadian dollar
+    pub const CAD: Self = Self::new("CAD", "CA$", 2);
+    /// Swiss franc
+    pub const CHF: Self = Self::new("CHF", "CHF\u{a0}", 2);
+    /// Chinese yuan
+    pub const CNY: Self = Self::new("CNY", "¥", 2);
+    /// Euro
+    pub const EUR: Self = Self::new("EUR", "€", 2);
+    /// Pound sterling
+    pub const GBP: Self = Self::new("GBP", "£", 2);
+    /// Hong Kong dollar
+    pub const HKD: Self = Self::new("HKD", "HK$", 2);
+    /// Indian rupee
+    pub const INR: Self = Self::new("INR", "₹", 2);
+    /// Japanese yen
+    pub const JPY: Self = Self::new("JPY", "¥", 0);
+    /// South Korean won
+    pub const KRW: Self = Self::new("KRW", "₩", 0);
+    /// Kuwaiti dinar
+    pub const KWD: Self = Self::new("KWD", "KWD\u{a0}", 3);
+    /// Mexican peso
+    pub const MXN: Self = Self::new("MXN", "MX$", 2);
+    /// Russian ruble
+    pub const RUB: Self = Self::new("RUB", "₽", 2);
+    /// Swedish krona
+    pub const SEK: Self = Self::new("SEK", "SEK\u{a0}", 2);
+    /// Singapore dollar
+    pub const SGD: Self = Self::new("SGD", "S$", 2);
+    /// US dollar
+    pub const USD: Self = Self::new("USD", "$", 2);
+
+    #[inline]
+    #[must_use]
+    /// Creates a currency.
+    pub const fn new(code: &'static str, symbol: &'static str, fraction_digits: u8) -> Self {
+        Self {
+            code,
+            symbol,
+            fraction_digits,
+            compact_threshold: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the amount in major units from which money is compacted, e.g.
+    /// `1_000_000` to show `$123,456.78` in full.
+    pub const fn with_compact_threshold(self, compact_threshold: u128) -> Self {
+        Self {
+            compact_threshold: Some(compact_threshold),
+            ..self
+        }
+    }
+
+    #[must_use]
+    /// Looks up a common currency by its ISO 4217 alphabetic code.
+    pub fn from_code(code: &str) -> Option<Self> {
+        CURRENCIES.into_iter().find(|currency| currency.code == code)
+    }
+
+    #[inline]
+    /// Returns the number of minor units in a major unit, e.g. `100`.
+    ///
+    /// Returns `None` if ten times it overflows `u128`, as the digits are
+    /// computed by multiplying the remainder by 10.
+    pub(super) const fn minor_per_major(&self) -> Option<u128> {
+        match 10_u128.checked_pow(self.fraction_digits as u32 + 1) {
+            Some(minor_per_major) => Some(minor_per_major / 10),
+            None => None,
+        }
+    }
+}
+
+/// The currencies looked up by [`Currency::from_code`].
+const CURRENCIES: [Currency; 19] = [
+    Currency::AED,
+    Currency::AUD,
+    Currency::BHD,
+    Currency::BRL,
+    Currency::CAD,
+    Currency::CHF,
+    Currency::CNY,
+    Currency::EUR,
+    Currency::GBP,
+    Currency::HKD,
+    Currency::INR,
+    Currency::JPY,
+    Currency::KRW,
+    Currency::KWD,
+    Currency::MXN,
+    Currency::RUB,
+    Currency::SEK,
+    Currency::SGD,
+    Currency::USD,
+];
@@ -0,0 +1,115 @@
+//! Alternate digit systems.
+
+use core::fmt::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Native digits, decimal mark and minus sign the number is transliterated
+/// into.
+///
+/// The ASCII digits and `-` of the sign and the number are replaced while
+/// writing, as is the `.` decimal mark of the [`Locale`](super::Locale). A
+/// locale with another decimal mark, and group separators, are kept as is.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::DigitSet;
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI.with_digit_set(DigitSet::DEVANAGARI);
+/// assert_eq!(formatter.format(1_500).to_string(), "१.५० K");
+///
+/// let formatter = Formatter::CHINESE
+///     .with_separator("")
+///     .with_digit_set(DigitSet::FULLWIDTH);
+/// assert_eq!(formatter.format(15_000).to_string(), "１．５０万");
+/// assert_eq!(formatter.format(-15_000).to_string(), "－１．５０万");
+/// ```
+pub struct DigitSet<'a> {
+    /// The digits from zero to nine.
+    pub digits: [char; 10],
+
+    /// Replaces the `.` decimal mark of the locale.
+    pub decimal_mark: &'a str,
+
+    /// Replaces the ASCII `-` minus sign.
+    pub minus: &'a str,
+}
+
+impl DigitSet<'_> {
+    /// Arabic-Indic digits, with Arabic decimal separator (U+066B) and Arabic
+    /// letter mark (U+061C) before the minus sign, e.g. `١٫٥٠`.
+    pub const ARABIC_INDIC: Self = Self {
+        digits: ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+        decimal_mark: "\u{66b}",
+        minus: "\u{61c}-",
+    };
+    /// ASCII digits, e.g. `1.50` (default).
+    pub const ASCII: Self = Self {
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal_mark: ".",
+        minus: "-",
+    };
+    /// Devanagari digits, e.g. `१.५०`.
+    pub const DEVANAGARI: Self = Self {
+        digits: ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'],
+        decimal_mark: ".",
+        minus: "-",
+    };
+    /// Extended Arabic-Indic digits used for Persian and Urdu, with Arabic
+    /// decimal separator (U+066B), e.g. `۱٫۵۰`.
+    pub const EXTENDED_ARABIC_INDIC: Self = Self {
+        digits: ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'],
+        decimal_mark: "\u{66b}",
+        minus: "\u{200e}\u{2212}",
+    };
+    /// Full-width digits, decimal mark and minus sign, e.g. `１．５０`.
+    pub const FULLWIDTH: Self = Self {
+        digits: ['０', '１', '２', '３', '４', '５', '６', '７', '８', '９'],
+        decimal_mark: "\u{ff0e}",
+        minus: "\u{ff0d}",
+    };
+
+    #[inline]
+    /// Whether this is [`DigitSet::ASCII`], which needs no transliteration.
+    pub(super) fn is_ascii(&self) -> bool {
+        *self == Self::ASCII
+    }
+}
+
+impl Default for DigitSet<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::ASCII
+    }
+}
+
+/// A writer transliterating ASCII digits and `-` with a [`DigitSet`].
+pub(super) struct Transliterate<'a, W> {
+    /// The underlying writer.
+    inner: &'a mut W,
+
+    /// The digit set.
+    digit_set: &'a DigitSet<'a>,
+}
+
+impl<'a, W: Write> Transliterate<'a, W> {
+    #[inline]
+    pub(super) fn new(inner: &'a mut W, digit_set: &'a DigitSet<'a>) -> Self {
+        Self { inner, digit_set }
+    }
+}
+
+impl<W: Write> Write for Transliterate<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '0'..='9' => self.inner.write_char(self.digit_set.digits[c as usize - '0' as usize]),
+            '-' => self.inner.write_str(self.digit_set.minus),
+            _ => self.inner.write_char(c),
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Locale-aware decimal mark and digit grouping.
+
+use core::fmt::{self, Write};
+use core::iter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Decimal mark and digit grouping of a locale.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::Locale;
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI.with_locale(Locale::DE);
+/// assert_eq!(formatter.format(1_500).to_string(), "1,50 K");
+/// assert_eq!(formatter.format(999.5).to_string(), "999,50");
+///
+/// let formatter = Formatter::CHINESE.with_locale(Locale::EN);
+/// assert_eq!(formatter.format(9_999).to_string(), "9,999");
+/// assert_eq!(formatter.format(99_990_000).to_string(), "9,999.00 万");
+///
+/// let formatter = Formatter::CHINESE.with_locale(Locale::FR);
+/// assert_eq!(
+///     formatter.format(12_345_678.9).to_string(),
+///     "1\u{202f}234,56 万"
+/// );
+/// ```
+pub struct Locale<'a> {
+    /// The decimal mark.
+    pub decimal_mark: &'a str,
+
+    /// The separator between digit groups of the integer part.
+    pub group_separator: &'a str,
+
+    /// The size of the digit group nearest to the decimal mark, `0` to disable
+    /// grouping.
+    pub primary_group: u8,
+
+    /// The size of the other digit groups, `0` for the same as
+    /// `primary_group`.
+    pub secondary_group: u8,
+
+    /// The minimum number of digits before the first group separator, e.g.
+    /// `2` to keep `1234` but group `12,345`.
+    pub min_grouping_digits: u8,
+}
+
+impl Locale<'_> {
+    /// Swiss, with apostrophe (U+2019) between groups, e.g. `12’345.67`.
+    pub const CH: Self = Self {
+        decimal_mark: ".",
+        group_separator: "\u{2019}",
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: 1,
+    };
+    /// German, e.g. `12.345,67`.
+    pub const DE: Self = Self {
+        decimal_mark: ",",
+        group_separator: ".",
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: 1,
+    };
+    /// ASCII period as the decimal mark, without grouping (default).
+    pub const DEFAULT: Self = Self {
+        decimal_mark: ".",
+        group_separator: "",
+        primary_group: 0,
+        secondary_group: 0,
+        min_grouping_digits: 1,
+    };
+    /// English, e.g. `12,345.67`.
+    pub const EN: Self = Self {
+        decimal_mark: ".",
+        group_separator: ",",
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: 1,
+    };
+    /// Spanish, e.g. `12.345,67`.
+    pub const ES: Self = Self {
+        decimal_mark: ",",
+        group_separator: ".",
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: 1,
+    };
+    /// French, with narrow no-break space (U+202F) between groups, e.g.
+    /// `12 345,67`.
+    pub const FR: Self = Self {
+        decimal_mark: ",",
+        group_separator: "\u{202f}",
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: 1,
+    };
+    /// Indian, with 2-2-3 grouping, e.g. `12,34,567.89`.
+    pub const IN: Self = Self {
+        decimal_mark: ".",
+        group_separator: ",",
+        primary_group: 3,
+        secondary_group: 2,
+        min_grouping_digits: 1,
+    };
+    /// Italian, e.g. `12.345,67`.
+    pub const IT: Self = Self {
+        decimal_mark: ",",
+        group_separator: ".",
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: 1,
+    };
+    /// Portuguese, e.g. `12.345,67`.
+    pub const PT: Self = Self {
+        decimal_mark: ",",
+        group_separator: ".",
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: 1,
+    };
+    /// Russian, with no-break space (U+00A0) between groups, e.g.
+    /// `12 345,67`.
+    pub const RU: Self = Self {
+        decimal_mark: ",",
+        group_separator: "\u{a0}",
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: 1,
+    };
+
+    /// Writes an ASCII number like `12345.67` or `1.5e20`, localized.
+    pub(super) fn write_number<W: Write>(&self, w: &mut W, number: &str) -> fmt::Result {
+        let (mantissa, exponent) = match number.find('e') {
+            Some(idx) => number.split_at(idx),
+            None => (number, ""),
+        };
+
+        let (integer, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        self.write_integer(w, integer)?;
+
+        if !fractional.is_empty() {
+            w.write_str(self.decimal_mark)?;
+            w.write_str(fractional)?;
+        }
+
+        w.write_str(exponent)
+    }
+
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    /// Writes an ASCII number like `12345.678` or `1.5e20`, localized, in
+    /// fixed notation with the fraction truncated or padded to
+    /// `decimal_places`.
+    ///
+    /// Numbers not finite, e.g. `inf`, are written as is.
+    pub(super) fn write_fixed<W: Write>(&self, w: &mut W, number: &str, decimal_places: usize) -> fmt::Result {
+        if !number.starts_with(|c: char| c.is_ascii_digit()) {
+            return w.write_str(number);
+        }
+
+        let (mantissa, exponent) = split_exponent(number);
+        let (integer, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits = || integer.chars().chain(fractional.chars());
+
+        // The number of digits before the decimal mark, once expanded.
+        let point = integer.len() as isize + exponent;
+
+        if point > 0 {
+            let point = point as usize;
+
+            self.write_grouped(w, digits().chain(iter::repeat('0')).take(point), point)?;
+        } else {
+            w.write_char('0')?;
+        }
+
+        if decimal_places > 0 {
+            w.write_str(self.decimal_mark)?;
+
+            let leading_zeros = iter::repeat_n('0', (-point).max(0) as usize);
+
+            leading_zeros
+                .chain(digits().skip(point.max(0) as usize))
+                .chain(iter::repeat('0'))
+                .take(decimal_places)
+                .try_for_each(|digit| w.write_char(digit))?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    /// Writes an unsigned integer, grouped.
+    pub(super) fn write_u128<W: Write>(&self, w: &mut W, integer: u128) -> fmt::Result {
+        // `u128::MAX` has 39 digits.
+        let mut buf = [0_u8; 39];
+        let mut idx = buf.len();
+        let mut integer = integer;
+
+        loop {
+            idx -= 1;
+            buf[idx] = b'0' + (integer % 10) as u8;
+            integer /= 10;
+
+            if integer == 0 {
+                break;
+            }
+        }
+
+        self.write_integer(w, core::str::from_utf8(&buf[idx..]).unwrap_or_default())
+    }
+
+    #[inline]
+    /// Writes the ASCII digits of an integer, grouped.
+    pub(super) fn write_integer<W: Write>(&self, w: &mut W, digits: &str) -> fmt::Result {
+        self.write_grouped(w, digits.chars(), digits.len())
+    }
+
+    /// Writes `len` ASCII digits of an integer, grouped.
+    fn write_grouped<W: Write>(&self, w: &mut W, digits: impl Iterator<Item = char>, len: usize) -> fmt::Result {
+        let primary = usize::from(self.primary_group);
+        let grouped = primary != 0 && len >= primary + usize::from(self.min_grouping_digits.max(1));
+
+        let secondary = match self.secondary_group {
+            0 => primary,
+            secondary => usize::from(secondary),
+        };
+
+        for (idx, digit) in digits.enumerate() {
+            // The number of digits from here to the decimal mark.
+            let remaining = len - idx;
+
+            if grouped && idx > 0 && remaining >= primary && (remaining - primary) % secondary == 0 {
+                w.write_str(self.group_separator)?;
+            }
+
+            w.write_char(digit)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Locale<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Splits an ASCII number like `1.5e20` into its mantissa and exponent.
+pub(super) fn split_exponent(number: &str) -> (&str, isize) {
+    match number.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse().unwrap_or_default()),
+        None => (number, 0),
+    }
+}
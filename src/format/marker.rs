@@ -0,0 +1,50 @@
+//! Approximation and saturation markers.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Markers prepended to a formatted number that is not exactly the input.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::Markers;
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI.with_markers(Markers::DEFAULT);
+/// assert_eq!(formatter.format(1_000).to_string(), "1.00 K");
+/// assert_eq!(formatter.format(1_234).to_string(), "~1.23 K");
+/// assert_eq!(formatter.format(0.001).to_string(), "<0.01");
+/// assert_eq!(formatter.format(1e33).to_string(), ">999.99 Q");
+/// ```
+pub struct Markers<'a> {
+    /// Prepended when the rendered number differs from the input, e.g.
+    /// `~1.23 K`.
+    pub approximate: &'a str,
+
+    /// Prepended when the input is less than the rendered number.
+    ///
+    /// This happens when a non-zero number rounds to zero (`<0.01`) or a
+    /// negative number exceeds the last unit (`<-999.99 Q`).
+    pub less_than: &'a str,
+
+    /// Prepended when the input is greater than the rendered number.
+    ///
+    /// This happens when a number exceeds the last unit (`>999.99 Q`) or a
+    /// non-zero negative number rounds to zero (`>-0.01`).
+    pub greater_than: &'a str,
+}
+
+impl Markers<'_> {
+    /// The default markers: `~`, `<` and `>`.
+    pub const DEFAULT: Self = Self {
+        approximate: "~",
+        less_than: "<",
+        greater_than: ">",
+    };
+}
+
+impl Default for Markers<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,333 @@
+//! Scientific and engineering notation.
+
+use core::fmt::{self, Write};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// How numbers are rendered.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::{ExponentStyle, Notation};
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI.with_notation(Notation::Scientific);
+/// assert_eq!(formatter.format(1_234_567_890).to_string(), "1.23e9");
+///
+/// let formatter = Formatter::SI.with_notation(Notation::Engineering);
+/// assert_eq!(formatter.format(12_345_678_900_u64).to_string(), "12.34e9");
+/// assert_eq!(
+///     formatter
+///         .with_exponent_style(ExponentStyle::Superscript)
+///         .format(0.000_123)
+///         .to_string(),
+///     "123.00×10⁻⁶"
+/// );
+/// ```
+pub enum Notation {
+    #[default]
+    /// Scales the number with the ranged units, e.g. `1.23 G`.
+    Units,
+
+    /// Scientific notation, e.g. `1.23e9`.
+    Scientific,
+
+    /// Engineering notation, where the exponent is a multiple of 3, e.g.
+    /// `12.34e9`.
+    Engineering,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// How the exponent of scientific or engineering notation is rendered.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::{ExponentStyle, Notation};
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI.with_notation(Notation::Scientific);
+///
+/// assert_eq!(
+///     formatter
+///         .with_exponent_style(ExponentStyle::Lowercase)
+///         .format(1_234_567_890)
+///         .to_string(),
+///     "1.23e9"
+/// );
+/// assert_eq!(
+///     formatter
+///         .with_exponent_style(ExponentStyle::Uppercase)
+///         .format(1_234_567_890)
+///         .to_string(),
+///     "1.23E+09"
+/// );
+/// assert_eq!(
+///     formatter
+///         .with_exponent_style(ExponentStyle::Superscript)
+///         .format(1_234_567_890)
+///         .to_string(),
+///     "1.23×10⁹"
+/// );
+/// assert_eq!(
+///     formatter
+///         .with_exponent_style(ExponentStyle::Latex)
+///         .format(1_234_567_890)
+///         .to_string(),
+///     r"1.23 \times 10^{9}"
+/// );
+/// ```
+pub enum ExponentStyle {
+    #[default]
+    /// ASCII `e`, e.g. `1.23e9`.
+    Lowercase,
+
+    /// ASCII `E` with a signed exponent of at least 2 digits, e.g. `1.23E+09`.
+    Uppercase,
+
+    /// Unicode superscript exponent, e.g. `1.23×10⁹`.
+    Superscript,
+
+    /// LaTeX math, e.g. `1.23 \times 10^{9}`.
+    Latex,
+}
+
+impl ExponentStyle {
+    /// Writes the exponent part.
+    fn write<W: Write>(self, w: &mut W, exponent: i32) -> fmt::Result {
+        match self {
+            Self::Lowercase => write!(w, "e{exponent}"),
+            Self::Uppercase => write!(w, "E{exponent:+03}"),
+            Self::Superscript => {
+                const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+                w.write_str("×10")?;
+
+                if exponent < 0 {
+                    w.write_char('⁻')?;
+                }
+
+                let exponent = exponent.unsigned_abs();
+                let mut divisor = 1;
+
+                while exponent / divisor >= 10 {
+                    divisor *= 10;
+                }
+
+                while divisor > 0 {
+                    w.write_char(SUPERSCRIPT_DIGITS[(exponent / divisor % 10) as usize])?;
+
+                    divisor /= 10;
+                }
+
+                Ok(())
+            }
+            Self::Latex => write!(w, r" \times 10^{{{exponent}}}"),
+        }
+    }
+}
+
+/// The significant digits and the decimal exponent of a finite number,
+/// parsed from its shortest representation.
+struct Significand<'a> {
+    /// The integer part of the shortest representation.
+    integer: &'a str,
+
+    /// The fractional part of the shortest representation.
+    fractional: &'a str,
+
+    /// The number of leading zeros before the first significant digit.
+    leading_zeros: usize,
+
+    /// The decimal exponent of the first significant digit.
+    exponent: i32,
+}
+
+impl<'a> Significand<'a> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    /// Parses the shortest representation of a non-negative finite number,
+    /// e.g. `123.45` or `1.2345e20`.
+    fn parse(formatted: &'a str) -> Self {
+        let (mantissa, exponent) = match formatted.split_once('e') {
+            Some((mantissa, exponent)) => (mantissa, exponent.parse().unwrap_or(0)),
+            None => (formatted, 0),
+        };
+
+        let (integer, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let leading_zeros = integer
+            .bytes()
+            .chain(fractional.bytes())
+            .take_while(|&digit| digit == b'0')
+            .count();
+
+        let exponent = if leading_zeros == integer.len() + fractional.len() {
+            // Zero
+            0
+        } else {
+            exponent + integer.len() as i32 - 1 - leading_zeros as i32
+        };
+
+        Self {
+            integer,
+            fractional,
+            leading_zeros,
+            exponent,
+        }
+    }
+
+    /// Returns the significant digit at `idx`, `b'0'` if out of range.
+    fn digit(&self, idx: usize) -> u8 {
+        let idx = idx + self.leading_zeros;
+
+        self.integer
+            .as_bytes()
+            .get(idx)
+            .or_else(|| self.fractional.as_bytes().get(idx - self.integer.len()))
+            .copied()
+            .unwrap_or(b'0')
+    }
+
+    /// Returns the number of significant digits.
+    const fn len(&self) -> usize {
+        self.integer.len() + self.fractional.len() - self.leading_zeros
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    /// Returns the number of integer digits and the exponent to be shown.
+    const fn split(&self, engineering: bool) -> (usize, i32) {
+        if engineering {
+            let shift = self.exponent.rem_euclid(3);
+
+            (1 + shift as usize, self.exponent - shift)
+        } else {
+            (1, self.exponent)
+        }
+    }
+}
+
+/// Writes a finite, non-negative `number` in scientific or engineering
+/// notation, with fixed `DECIMAL_PLACES`.
+pub(super) fn write_exponent<const DECIMAL_PLACES: usize, W: Write>(
+    w: &mut W,
+    number: f64,
+    engineering: bool,
+    style: ExponentStyle,
+    decimal_mark: &str,
+) -> fmt::Result {
+    let formatted = ryuu::Formatter::format_finite_f64(number);
+
+    write_significand::<DECIMAL_PLACES, W>(w, formatted.as_str(), engineering, style, decimal_mark)
+}
+
+/// Writes `integer / 10^scale` in scientific or engineering notation, with
+/// fixed `DECIMAL_PLACES`, computed from the integer without `f64`
+/// conversion.
+pub(super) fn write_exponent_exact<const DECIMAL_PLACES: usize, W: Write>(
+    w: &mut W,
+    integer: u128,
+    scale: u8,
+    engineering: bool,
+    style: ExponentStyle,
+    decimal_mark: &str,
+) -> fmt::Result {
+    let exact = Exact::new(integer, scale);
+
+    write_significand::<DECIMAL_PLACES, W>(w, exact.as_str(), engineering, style, decimal_mark)
+}
+
+/// Writes the significant digits of a representation like `123.45` or
+/// `12345e-2` in scientific or engineering notation, with fixed
+/// `DECIMAL_PLACES`.
+fn write_significand<const DECIMAL_PLACES: usize, W: Write>(
+    w: &mut W,
+    formatted: &str,
+    engineering: bool,
+    style: ExponentStyle,
+    decimal_mark: &str,
+) -> fmt::Result {
+    let significand = Significand::parse(formatted);
+    let (integer_digits, exponent) = significand.split(engineering);
+
+    for idx in 0..integer_digits {
+        w.write_char(significand.digit(idx) as char)?;
+    }
+
+    if DECIMAL_PLACES > 0 {
+        w.write_str(decimal_mark)?;
+
+        for idx in integer_digits..integer_digits + DECIMAL_PLACES {
+            w.write_char(significand.digit(idx) as char)?;
+        }
+    }
+
+    style.write(w, exponent)
+}
+
+/// Whether a finite `number` in scientific or engineering notation has
+/// non-zero digits beyond `DECIMAL_PLACES`.
+pub(super) fn is_exponent_truncated<const DECIMAL_PLACES: usize>(number: f64, engineering: bool) -> bool {
+    let formatted = ryuu::Formatter::format_finite_f64(number);
+    let formatted = formatted.as_str();
+
+    is_significand_truncated::<DECIMAL_PLACES>(formatted.strip_prefix('-').unwrap_or(formatted), engineering)
+}
+
+/// Whether `integer / 10^scale` in scientific or engineering notation has
+/// non-zero digits beyond `DECIMAL_PLACES`.
+pub(super) fn is_exponent_exact_truncated<const DECIMAL_PLACES: usize>(
+    integer: u128,
+    scale: u8,
+    engineering: bool,
+) -> bool {
+    is_significand_truncated::<DECIMAL_PLACES>(Exact::new(integer, scale).as_str(), engineering)
+}
+
+/// Whether the significant digits of a non-negative representation have
+/// non-zero digits beyond `DECIMAL_PLACES`.
+fn is_significand_truncated<const DECIMAL_PLACES: usize>(formatted: &str, engineering: bool) -> bool {
+    let significand = Significand::parse(formatted);
+    let (integer_digits, _) = significand.split(engineering);
+
+    (integer_digits + DECIMAL_PLACES..significand.len()).any(|idx| significand.digit(idx) != b'0')
+}
+
+/// The exact representation of `integer / 10^scale`, e.g. `12345e-2`.
+struct Exact {
+    /// The ASCII representation, up to 39 digits and `e-255`.
+    bytes: [u8; 44],
+
+    /// The length of the representation.
+    len: usize,
+}
+
+impl Exact {
+    /// Writes the representation of `integer / 10^scale`.
+    fn new(integer: u128, scale: u8) -> Self {
+        let mut exact = Self { bytes: [0; 44], len: 0 };
+
+        // The buffer holds any `u128` and `u8`.
+        let _ = write!(exact, "{integer}e-{scale}");
+
+        exact
+    }
+
+    /// Returns the representation.
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for Exact {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
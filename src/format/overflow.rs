@@ -0,0 +1,102 @@
+//! Overflow policy, for numbers exceeding the last unit.
+
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// What to do with a number exceeding the last unit.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::Overflow;
+/// use humat::Formatter;
+///
+/// let target = 1_000_000_000_000_000_000_000_000_000_000_000_u128;
+///
+/// assert_eq!(Formatter::SI.format(target).to_string(), "1e33");
+/// assert_eq!(
+///     Formatter::SI
+///         .with_overflow(Overflow::Extend)
+///         .format(target)
+///         .to_string(),
+///     "1000.00 Q"
+/// );
+/// assert_eq!(
+///     Formatter::SI
+///         .with_overflow(Overflow::Scientific)
+///         .format(target * 12)
+///         .to_string(),
+///     "1.20e34"
+/// );
+/// assert_eq!(
+///     Formatter::SI
+///         .with_overflow(Overflow::Engineering)
+///         .format(target * 12)
+///         .to_string(),
+///     "12.00e33"
+/// );
+/// assert!(Formatter::SI
+///     .with_overflow(Overflow::Error)
+///     .try_format(target)
+///     .is_err());
+/// ```
+pub enum Overflow {
+    #[default]
+    /// Prints the number without unit.
+    ///
+    /// If [`Markers`](super::Markers) are enabled, the number saturates to the
+    /// largest number the last unit can show instead, e.g. `>999.99 Q`.
+    Unscaled,
+
+    /// Keeps scaling with the last unit, e.g. `1000.00 Q`, in fixed notation to
+    /// the precision of `f64`.
+    Extend,
+
+    /// Switches to scientific notation, e.g. `1.00e33`.
+    ///
+    /// The exponent is rendered with the formatter's
+    /// [`ExponentStyle`](super::ExponentStyle).
+    Scientific,
+
+    /// Switches to engineering notation, where the exponent is a multiple of 3,
+    /// e.g. `12.00e33`.
+    ///
+    /// The exponent is rendered with the formatter's
+    /// [`ExponentStyle`](super::ExponentStyle).
+    Engineering,
+
+    /// Reports an [`OverflowError`] through the fallible API, like
+    /// [`Formatter::try_format`](super::Formatter::try_format).
+    ///
+    /// The infallible API prints the number without unit.
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The number exceeds the last unit, see [`Overflow::Error`].
+pub struct OverflowError {
+    /// The number.
+    number: f64,
+}
+
+impl OverflowError {
+    #[inline]
+    pub(super) const fn new(number: f64) -> Self {
+        Self { number }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number exceeding the last unit, as a `f64`.
+    pub const fn number(&self) -> f64 {
+        self.number
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "number {} exceeds the last unit", self.number)
+    }
+}
+
+impl core::error::Error for OverflowError {}
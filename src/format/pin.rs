@@ -0,0 +1,32 @@
+//! Unit choice for a set of values.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Which value of a set chooses the unit shared by the set.
+///
+/// See [`Formatter::pin`](super::Formatter::pin).
+pub enum PinBy {
+    #[default]
+    /// The largest value, so no value exceeds the unit (default).
+    Max,
+
+    /// The median value, so half of the values are at least `1` in the unit.
+    Median,
+
+    /// The value at the given percentile, from `0` to `100`, using the
+    /// nearest-rank method.
+    Percentile(u8),
+}
+
+impl PinBy {
+    #[inline]
+    /// Returns the zero-based rank of the chosen value among `len` values.
+    pub(super) fn rank(self, len: usize) -> usize {
+        let percentile = match self {
+            Self::Max => 100,
+            Self::Median => 50,
+            Self::Percentile(percentile) => usize::from(percentile.min(100)),
+        };
+
+        (len * percentile).div_ceil(100).clamp(1, len) - 1
+    }
+}
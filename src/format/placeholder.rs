@@ -0,0 +1,64 @@
+//! Placeholders for non-finite, negative zero and missing numbers.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Placeholders rendered verbatim, without unit, instead of the number.
+///
+/// Non-finite numbers without placeholder are rendered as is with the custom
+/// unit, e.g. `NaN B`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::Placeholders;
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI
+///     .with_custom_unit("B")
+///     .with_placeholders(Placeholders {
+///         infinity: Some("∞"),
+///         neg_infinity: Some("-∞"),
+///         missing: "—",
+///         ..Placeholders::DEFAULT
+///     });
+///
+/// assert_eq!(formatter.format(f64::NAN).to_string(), "NaN B");
+/// assert_eq!(formatter.format(f64::INFINITY).to_string(), "∞");
+/// assert_eq!(formatter.format(Some(1_000)).to_string(), "1.00 KB");
+/// assert_eq!(formatter.format(None::<u64>).to_string(), "—");
+/// ```
+pub struct Placeholders<'a> {
+    /// Rendered for NaN, which is rendered like `NaN` by default.
+    pub nan: Option<&'a str>,
+
+    /// Rendered for positive infinity, which is rendered like `inf` by
+    /// default.
+    pub infinity: Option<&'a str>,
+
+    /// Rendered for negative infinity, which is rendered like `-inf` by
+    /// default.
+    pub neg_infinity: Option<&'a str>,
+
+    /// Rendered for `-0.0`, which is rendered like `-0.00` by default.
+    pub neg_zero: Option<&'a str>,
+
+    /// Rendered for missing numbers, i.e. `None`, defaults to an empty string.
+    pub missing: &'a str,
+}
+
+impl Placeholders<'_> {
+    /// The default placeholders.
+    pub const DEFAULT: Self = Self {
+        nan: None,
+        infinity: None,
+        neg_infinity: None,
+        neg_zero: None,
+        missing: "",
+    };
+}
+
+impl Default for Placeholders<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,69 @@
+//! Placement of prefix, suffix and units.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Where the units are placed relative to the number.
+pub enum UnitPosition {
+    #[default]
+    /// After the number, e.g. `1.20 M`.
+    After,
+
+    /// Before the number, e.g. `M 1.20`.
+    Before,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Placement of a prefix, e.g. a currency symbol, a suffix and the units.
+///
+/// The sign is rendered before the prefix, e.g. `-$1.20 M`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::Placement;
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI.with_placement(Placement {
+///     prefix: "$",
+///     ..Placement::DEFAULT
+/// });
+/// assert_eq!(formatter.format(1_200_000).to_string(), "$1.20 M");
+/// assert_eq!(formatter.format(-1_200_000).to_string(), "-$1.20 M");
+///
+/// let formatter = Formatter::SI.with_placement(Placement {
+///     suffix: " USD",
+///     ..Placement::DEFAULT
+/// });
+/// assert_eq!(formatter.format(1_200_000).to_string(), "1.20 M USD");
+/// ```
+pub struct Placement<'a> {
+    /// Rendered after the sign, before the number and any leading units.
+    pub prefix: &'a str,
+
+    /// Rendered after the number and any trailing units.
+    pub suffix: &'a str,
+
+    /// Where the units are placed relative to the number.
+    pub unit_position: UnitPosition,
+}
+
+impl Placement<'_> {
+    /// No prefix or suffix, units after the number (default).
+    pub const DEFAULT: Self = Self {
+        prefix: "",
+        suffix: "",
+        unit_position: UnitPosition::After,
+    };
+    /// No prefix or suffix, units before the number, e.g. for right-to-left
+    /// units with [`Bidi::RightToLeftMark`](super::Bidi::RightToLeftMark).
+    pub const UNITS_BEFORE: Self = Self {
+        unit_position: UnitPosition::Before,
+        ..Self::DEFAULT
+    };
+}
+
+impl Default for Placement<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
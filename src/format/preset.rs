@@ -4,119 +4,77 @@ use core::num::NonZeroU128;
 
 use crate::unit::RangedUnit;
 
-pub(super) static BINARY_UNITS: [RangedUnit; 9] = [
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(1)).unwrap(),
-        unit: None,
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(2)).unwrap(),
-        unit: Some("Ki"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(3)).unwrap(),
-        unit: Some("Mi"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(4)).unwrap(),
-        unit: Some("Gi"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(5)).unwrap(),
-        unit: Some("Ti"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(6)).unwrap(),
-        unit: Some("Pi"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(7)).unwrap(),
-        unit: Some("Ei"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(8)).unwrap(),
-        unit: Some("Zi"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_024_u128.pow(9)).unwrap(),
-        unit: Some("Yi"),
-    },
-];
+const BINARY_BASE: NonZeroU128 = NonZeroU128::new(1_024).unwrap();
 
-pub(super) static CHINESE_UNITS: [RangedUnit; 9] = [
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(1)).unwrap(),
-        unit: None,
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(2)).unwrap(),
-        unit: Some("万"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(3)).unwrap(),
-        unit: Some("亿"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(4)).unwrap(),
-        unit: Some("兆"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(5)).unwrap(),
-        unit: Some("京"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(6)).unwrap(),
-        unit: Some("垓"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(7)).unwrap(),
-        unit: Some("秭"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(8)).unwrap(),
-        unit: Some("穰"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(10_000_u128.pow(9)).unwrap(),
-        unit: Some("沟"),
-    },
-];
+const CHINESE_BASE: NonZeroU128 = NonZeroU128::new(10_000).unwrap();
 
-pub(super) static SI_UNITS: [RangedUnit; 9] = [
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(1)).unwrap(),
-        unit: None,
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(2)).unwrap(),
-        unit: Some("K"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(3)).unwrap(),
-        unit: Some("M"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(4)).unwrap(),
-        unit: Some("G"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(5)).unwrap(),
-        unit: Some("T"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(6)).unwrap(),
-        unit: Some("P"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(7)).unwrap(),
-        unit: Some("E"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(8)).unwrap(),
-        unit: Some("Z"),
-    },
-    RangedUnit {
-        range_max: NonZeroU128::new(1_000_u128.pow(9)).unwrap(),
-        unit: Some("Y"),
-    },
-];
+const SI_BASE: NonZeroU128 = NonZeroU128::new(1_000).unwrap();
+
+pub(super) static BINARY_UNITS: [RangedUnit; 9] = RangedUnit::geometric(
+    BINARY_BASE,
+    [
+        None,
+        Some("Ki"),
+        Some("Mi"),
+        Some("Gi"),
+        Some("Ti"),
+        Some("Pi"),
+        Some("Ei"),
+        Some("Zi"),
+        Some("Yi"),
+    ],
+);
+
+pub(super) static CHINESE_UNITS: [RangedUnit; 13] = RangedUnit::geometric(
+    CHINESE_BASE,
+    [
+        None,
+        Some("万"),
+        Some("亿"),
+        Some("兆"),
+        Some("京"),
+        Some("垓"),
+        Some("秭"),
+        Some("穰"),
+        Some("沟"),
+        Some("涧"),
+        Some("正"),
+        Some("载"),
+        Some("极"),
+    ],
+);
+
+pub(super) static SI_UNITS: [RangedUnit; 11] = RangedUnit::geometric(
+    SI_BASE,
+    [
+        None,
+        Some("K"),
+        Some("M"),
+        Some("G"),
+        Some("T"),
+        Some("P"),
+        Some("E"),
+        Some("Z"),
+        Some("Y"),
+        Some("R"),
+        Some("Q"),
+    ],
+);
+
+pub(super) static GERMAN_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(SI_BASE, [None, Some("Tsd"), Some("Mio"), Some("Mrd"), Some("Bio")]);
+
+pub(super) static FRENCH_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(SI_BASE, [None, Some("k"), Some("M"), Some("Md"), Some("Bn")]);
+
+pub(super) static SPANISH_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(SI_BASE, [None, Some("mil"), Some("M"), Some("mil M"), Some("B")]);
+
+pub(super) static RUSSIAN_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(SI_BASE, [None, Some("тыс."), Some("млн"), Some("млрд"), Some("трлн")]);
+
+pub(super) static PORTUGUESE_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(SI_BASE, [None, Some("mil"), Some("mi"), Some("bi"), Some("tri")]);
+
+pub(super) static ITALIAN_UNITS: [RangedUnit; 5] =
+    RangedUnit::geometric(SI_BASE, [None, Some("mila"), Some("Mln"), Some("Mrd"), Some("Bln")]);
@@ -0,0 +1,68 @@
+//! Sign styles.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How the sign of a formatted number is rendered.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::format::SignStyle;
+/// use humat::Formatter;
+///
+/// let formatter = Formatter::SI;
+/// assert_eq!(formatter.format(-1_200).to_string(), "-1.20 K");
+///
+/// let formatter = Formatter::SI.with_sign_style(SignStyle::MINUS);
+/// assert_eq!(formatter.format(-1_200).to_string(), "−1.20 K");
+///
+/// let formatter = Formatter::SI.with_sign_style(SignStyle::PARENTHESES);
+/// assert_eq!(formatter.format(-1_200).to_string(), "(1.20 K)");
+///
+/// let formatter = Formatter::SI.with_sign_style(SignStyle::PLUS);
+/// assert_eq!(formatter.format(1_200).to_string(), "+1.20 K");
+/// assert_eq!(formatter.format(-1_200).to_string(), "-1.20 K");
+/// ```
+pub struct SignStyle<'a> {
+    /// Prepended to negative numbers.
+    pub negative: &'a str,
+
+    /// Appended to negative numbers, after the units.
+    pub negative_suffix: &'a str,
+
+    /// Prepended to non-negative numbers.
+    pub positive: &'a str,
+}
+
+impl SignStyle<'_> {
+    /// ASCII hyphen-minus for negative numbers, e.g. `-1.20 K` (default).
+    pub const HYPHEN: Self = Self {
+        negative: "-",
+        negative_suffix: "",
+        positive: "",
+    };
+    /// Unicode minus sign (U+2212) for negative numbers, e.g. `−1.20 K`.
+    pub const MINUS: Self = Self {
+        negative: "\u{2212}",
+        negative_suffix: "",
+        positive: "",
+    };
+    /// Accounting parentheses for negative numbers, e.g. `(1.20 K)`.
+    pub const PARENTHESES: Self = Self {
+        negative: "(",
+        negative_suffix: ")",
+        positive: "",
+    };
+    /// Explicit plus sign for non-negative numbers, e.g. `+1.20 K`.
+    pub const PLUS: Self = Self {
+        negative: "-",
+        negative_suffix: "",
+        positive: "+",
+    };
+}
+
+impl Default for SignStyle<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::HYPHEN
+    }
+}
@@ -1,15 +1,802 @@
 //! Ranged unit
 
+use core::fmt;
 use core::num::NonZeroU128;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 /// Ranged unit.
-pub struct RangedUnit {
+pub struct RangedUnit<'a> {
     /// The maximum value of the range (excluded).
     ///
     /// This will be the minimum value of the next range.
-    pub range_max: NonZeroU128,
+    pub range_max: Magnitude,
 
     /// The abbr unit when the value is less than `range_max`.
-    pub unit: Option<&'static str>,
+    pub unit: Option<&'a str>,
+}
+
+impl<'a> RangedUnit<'a> {
+    #[must_use]
+    /// Creates ranged units where the nth `range_max` is the nth power of
+    /// `base`.
+    ///
+    /// See also [`ladder!`](crate::ladder!), which validates the ranged units
+    /// and creates the formatter at compile time.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU128;
+    ///
+    /// use humat::unit::RangedUnit;
+    /// use humat::Formatter;
+    ///
+    /// static UNITS: [RangedUnit; 3] = RangedUnit::geometric(
+    ///     NonZeroU128::new(1_024).unwrap(),
+    ///     [None, Some("Ki"), Some("Mi")],
+    /// );
+    ///
+    /// let formatter = Formatter::custom(&UNITS).unwrap();
+    /// assert_eq!(formatter.format(1_536).to_string(), "1.50 Ki");
+    /// ```
+    pub const fn geometric<const N: usize>(base: NonZeroU128, units: [Option<&'a str>; N]) -> [Self; N] {
+        let mut ranged_units = [Self {
+            range_max: Magnitude::new(base, 1),
+            unit: None,
+        }; N];
+
+        let mut i = 0;
+
+        while i < N {
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                ranged_units[i] = Self {
+                    range_max: Magnitude::new(base, i as u32 + 1),
+                    unit: units[i],
+                };
+            }
+
+            i += 1;
+        }
+
+        ranged_units
+    }
+}
+
+/// Creates a formatter from a base and the units, validated at compile time.
+///
+/// The nth `range_max` is the nth power of the base, and `_` means no unit,
+/// which is usual for the first one. An invalid base panics at compile time
+/// with the [`LadderError`] message.
+///
+/// ## Examples
+///
+/// ```rust
+/// use humat::Formatter;
+///
+/// const BINARY: Formatter = humat::ladder!(base = 1024, units = [_, "Ki", "Mi", "Gi"]);
+///
+/// assert_eq!(BINARY.format(1_536).to_string(), "1.50 Ki");
+/// assert_eq!(BINARY.format(1_048_576).to_string(), "1.00 Mi");
+///
+/// let seconds = humat::ladder!(base = 60, units = ["s", "min"]).with_separator("");
+/// assert_eq!(seconds.format(90).to_string(), "1.50min");
+/// ```
+///
+/// ```rust,compile_fail
+/// let formatter = humat::ladder!(base = 0, units = [_, "K"]);
+/// ```
+#[macro_export]
+macro_rules! ladder {
+    (base = $base:expr, units = [$($unit:tt),+ $(,)?] $(,)?) => {{
+        const BASE: ::core::num::NonZeroU128 = match ::core::num::NonZeroU128::new($base) {
+            ::core::option::Option::Some(base) => base,
+            ::core::option::Option::None => ::core::panic!("{}", $crate::unit::LadderError::ZeroBase.message()),
+        };
+
+        static UNITS: [$crate::unit::RangedUnit<'static>; [$($crate::ladder!(@count $unit)),+].len()] =
+            $crate::unit::RangedUnit::geometric(BASE, [$($crate::ladder!(@unit $unit)),+]);
+
+        const FORMATTER: $crate::Formatter<'static> = match $crate::Formatter::custom(&UNITS) {
+            ::core::result::Result::Ok(formatter) => formatter,
+            ::core::result::Result::Err(error) => ::core::panic!("{}", error.message()),
+        };
+
+        FORMATTER
+    }};
+    (@count $unit:tt) => {
+        ()
+    };
+    (@unit _) => {
+        ::core::option::Option::None
+    };
+    (@unit $unit:literal) => {
+        ::core::option::Option::Some($unit)
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+/// A magnitude `base^exponent`, which may exceed the range of `u128`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use core::num::NonZeroU128;
+///
+/// use humat::unit::Magnitude;
+///
+/// let base = NonZeroU128::new(10_000).unwrap();
+///
+/// assert_eq!(Magnitude::new(base, 9).get(), Some(10_u128.pow(36)));
+/// assert_eq!(Magnitude::new(base, 10).get(), None);
+/// assert_eq!(Magnitude::new(base, 10).to_f64(), 1e40);
+/// ```
+pub struct Magnitude {
+    /// The base.
+    base: NonZeroU128,
+
+    /// The exponent.
+    exponent: u32,
+
+    /// The exact value, if it does not exceed `u128`.
+    exact: Option<u128>,
+
+    /// The value as an `f64`.
+    float: f64,
+}
+
+impl Magnitude {
+    /// The magnitude `1`, i.e. no scaling.
+    pub const ONE: Self = Self::new(NonZeroU128::MIN, 0);
+
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    /// Creates a magnitude `base^exponent`.
+    pub const fn new(base: NonZeroU128, exponent: u32) -> Self {
+        // The value is `mantissa * 2^shift`, where the mantissa is halved until
+        // the product fits, so the `f64` is rounded only once at last.
+        let mut mantissa = 1_u128;
+        let mut shift = 0;
+
+        let mut i = 0;
+
+        while i < exponent {
+            while mantissa.checked_mul(base.get()).is_none() {
+                mantissa = (mantissa >> 1) + (mantissa & 1);
+                shift += 1;
+            }
+
+            mantissa *= base.get();
+            i += 1;
+        }
+
+        let mut float = mantissa as f64;
+
+        let mut i = 0;
+
+        while i < shift {
+            float *= 2.0;
+            i += 1;
+        }
+
+        Self {
+            base,
+            exponent,
+            exact: if shift == 0 { Some(mantissa) } else { None },
+            float,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the base.
+    pub const fn base(&self) -> NonZeroU128 {
+        self.base
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the exponent.
+    pub const fn exponent(&self) -> u32 {
+        self.exponent
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the exact value, or `None` if it exceeds `u128`.
+    pub const fn get(&self) -> Option<u128> {
+        self.exact
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the value as an `f64`, which may be imprecise or infinite.
+    pub const fn to_f64(&self) -> f64 {
+        self.float
+    }
+
+    #[inline]
+    /// Whether `target` is less than this magnitude.
+    pub(crate) const fn exceeds(&self, target: u128) -> bool {
+        match self.exact {
+            Some(value) => target < value,
+            None => true,
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    /// Returns this magnitude divided by `divisor`, saturating at `u128::MAX`.
+    pub(crate) const fn ratio(&self, divisor: &Self) -> u128 {
+        match (self.exact, divisor.exact) {
+            (Some(this), Some(divisor)) => this / divisor,
+            _ if self.base.get() == divisor.base.get() && self.exponent >= divisor.exponent => {
+                match Self::new(self.base, self.exponent - divisor.exponent).exact {
+                    Some(ratio) => ratio,
+                    None => u128::MAX,
+                }
+            }
+            _ => (self.float / divisor.float) as u128,
+        }
+    }
+
+    #[inline]
+    /// Whether this magnitude is less than `other`.
+    ///
+    /// Magnitudes exceeding `u128` are compared as `f64`.
+    pub(crate) const fn less_than(&self, other: &Self) -> bool {
+        match (self.exact, other.exact) {
+            (Some(this), Some(other)) => this < other,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => self.float < other.float,
+        }
+    }
+
+    #[inline]
+    /// Whether this magnitude equals `other`.
+    ///
+    /// Magnitudes exceeding `u128` are only equal when they have the same base
+    /// and exponent.
+    pub(crate) const fn equals(&self, other: &Self) -> bool {
+        match (self.exact, other.exact) {
+            (Some(this), Some(other)) => this == other,
+            (None, None) => self.base.get() == other.base.get() && self.exponent == other.exponent,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Error returned by [`Formatter::custom`](crate::Formatter::custom) for
+/// invalid ranged units.
+///
+/// ## Examples
+///
+/// In const context, the error can be turned into a compile-time panic:
+///
+/// ```rust
+/// use core::num::NonZeroU128;
+///
+/// use humat::unit::RangedUnit;
+/// use humat::Formatter;
+///
+/// const BASE: NonZeroU128 = NonZeroU128::new(60).unwrap();
+///
+/// static UNITS: [RangedUnit; 2] = RangedUnit::geometric(BASE, [Some("s"), Some("min")]);
+///
+/// const FORMATTER: Formatter = match Formatter::custom(&UNITS) {
+///     Ok(formatter) => formatter,
+///     Err(error) => panic!("{}", error.message()),
+/// };
+///
+/// assert_eq!(FORMATTER.format(90).to_string(), "1.50 min");
+/// ```
+pub enum LadderError {
+    /// No ranged units are given.
+    Empty,
+
+    /// The base, i.e. the first `range_max`, exceeds `u128`.
+    BaseOverflow,
+
+    /// The base, i.e. the first `range_max`, is zero.
+    ZeroBase,
+
+    /// The `range_max` at `index` is not greater than the previous one.
+    NonMonotonic {
+        /// The index of the ranged unit.
+        index: usize,
+    },
+
+    /// The `range_max` at `index` is not the `index + 1`th power of the base.
+    NotPowerOfBase {
+        /// The index of the ranged unit.
+        index: usize,
+    },
+}
+
+impl LadderError {
+    #[must_use]
+    /// Returns the description of the error, without the index.
+    ///
+    /// This is usable in const context, e.g. `panic!("{}", error.message())`.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::Empty => "no ranged units are given",
+            Self::BaseOverflow => "the base exceeds u128",
+            Self::ZeroBase => "the base is zero",
+            Self::NonMonotonic { .. } => "a range_max is not greater than the previous one",
+            Self::NotPowerOfBase { .. } => "a range_max is not the power of the base",
+        }
+    }
+}
+
+impl fmt::Display for LadderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonMonotonic { index } | Self::NotPowerOfBase { index } => {
+                write!(f, "{} (ranged unit {index})", self.message())
+            }
+            Self::Empty | Self::BaseOverflow | Self::ZeroBase => f.write_str(self.message()),
+        }
+    }
+}
+
+impl core::error::Error for LadderError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A ranged unit given by its index or its name, see
+/// [`Formatter::format_in_unit`](crate::Formatter::format_in_unit).
+pub enum UnitRef<'a> {
+    /// The index of the ranged unit.
+    Index(usize),
+
+    /// The name of the ranged unit, empty for no unit.
+    Name(&'a str),
+}
+
+impl From<usize> for UnitRef<'_> {
+    #[inline]
+    fn from(idx: usize) -> Self {
+        Self::Index(idx)
+    }
+}
+
+impl<'a> From<&'a str> for UnitRef<'a> {
+    #[inline]
+    fn from(name: &'a str) -> Self {
+        Self::Name(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// The unit selected by a [`UnitSystem`] for a number.
+pub enum Selection<'a> {
+    /// The number is below `ranged_unit.range_max`, and is divided by
+    /// `divisor`.
+    Unit {
+        /// The divisor of the number, e.g. `1000` for `K`.
+        divisor: Magnitude,
+
+        /// The unit, and the exclusive maximum of the number before division.
+        ranged_unit: RangedUnit<'a>,
+
+        /// The decimal places of the unit, overriding `DECIMAL_PLACES`.
+        decimal_places: Option<u8>,
+    },
+
+    /// The number exceeds the largest unit, and the
+    /// [`Overflow`](crate::format::Overflow) policy applies.
+    Overflow {
+        /// The divisor of the largest unit.
+        divisor: Magnitude,
+
+        /// The largest unit.
+        ranged_unit: RangedUnit<'a>,
+
+        /// The decimal places of the largest unit, overriding
+        /// `DECIMAL_PLACES`.
+        decimal_places: Option<u8>,
+    },
+}
+
+/// Selection of the unit and the divisor for a number.
+///
+/// Ranged units, i.e. `[RangedUnit]` and `[RangedUnit; N]`, are the default
+/// implementation. Implement it for units computed rather than tabulated, and
+/// plug it in with
+/// [`Formatter::with_unit_system`](crate::Formatter::with_unit_system).
+///
+/// The formatter is `Copy`, so is the unit system, e.g. a reference.
+///
+/// ## Examples
+///
+/// ```rust
+/// use core::num::NonZeroU128;
+///
+/// use humat::unit::{Magnitude, RangedUnit, Selection, UnitSystem};
+/// use humat::Formatter;
+///
+/// /// Credits and debits of a ledger, in thousands.
+/// #[derive(Clone, Copy)]
+/// struct Ledger;
+///
+/// impl UnitSystem<'static> for Ledger {
+///     fn select(&self, magnitude: f64, positive: bool) -> Selection<'static> {
+///         let unit = if positive { "K CR" } else { "K DR" };
+///
+///         if magnitude < 1e12 {
+///             Selection::Unit {
+///                 divisor: Magnitude::new(NonZeroU128::new(1_000).unwrap(), 1),
+///                 ranged_unit: RangedUnit {
+///                     range_max: Magnitude::new(NonZeroU128::new(10).unwrap(), 12),
+///                     unit: Some(unit),
+///                 },
+///                 decimal_places: None,
+///             }
+///         } else {
+///             Selection::Overflow {
+///                 divisor: Magnitude::ONE,
+///                 ranged_unit: RangedUnit {
+///                     range_max: Magnitude::ONE,
+///                     unit: None,
+///                 },
+///                 decimal_places: None,
+///             }
+///         }
+///     }
+/// }
+///
+/// let formatter = Formatter::SI.with_unit_system(Ledger);
+///
+/// assert_eq!(formatter.format(1_500).to_string(), "1.50 K CR");
+/// assert_eq!(formatter.format(-1_500).to_string(), "-1.50 K DR");
+/// ```
+pub trait UnitSystem<'a> {
+    /// Selects the unit for a finite, non-negative number, with the sign of
+    /// the original number.
+    fn select(&self, magnitude: f64, positive: bool) -> Selection<'a>;
+
+    #[allow(clippy::cast_precision_loss)]
+    /// Selects the unit for an integer, with the sign of the original number.
+    ///
+    /// Defaults to [`UnitSystem::select`] with the integer as `f64`.
+    fn select_uint(&self, magnitude: u128, positive: bool) -> Selection<'a> {
+        self.select(magnitude as f64, positive)
+    }
+}
+
+impl<'a> UnitSystem<'a> for [RangedUnit<'a>] {
+    #[inline]
+    fn select(&self, magnitude: f64, _positive: bool) -> Selection<'a> {
+        select_ranged(self, Target::Double(magnitude))
+    }
+
+    #[inline]
+    fn select_uint(&self, magnitude: u128, _positive: bool) -> Selection<'a> {
+        select_ranged(self, Target::Uint(magnitude))
+    }
+}
+
+impl<'a, const N: usize> UnitSystem<'a> for [RangedUnit<'a>; N] {
+    #[inline]
+    fn select(&self, magnitude: f64, positive: bool) -> Selection<'a> {
+        self.as_slice().select(magnitude, positive)
+    }
+
+    #[inline]
+    fn select_uint(&self, magnitude: u128, positive: bool) -> Selection<'a> {
+        self.as_slice().select_uint(magnitude, positive)
+    }
+}
+
+impl<'a, T: UnitSystem<'a> + ?Sized> UnitSystem<'a> for &T {
+    #[inline]
+    fn select(&self, magnitude: f64, positive: bool) -> Selection<'a> {
+        (**self).select(magnitude, positive)
+    }
+
+    #[inline]
+    fn select_uint(&self, magnitude: u128, positive: bool) -> Selection<'a> {
+        (**self).select_uint(magnitude, positive)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Ranged units, optionally clamped to a sub-range, the default unit system of
+/// [`Formatter`](crate::Formatter).
+///
+/// See [`Formatter::with_min_unit`](crate::Formatter::with_min_unit),
+/// [`Formatter::with_max_unit`](crate::Formatter::with_max_unit),
+/// [`Formatter::with_promotion`](crate::Formatter::with_promotion) and
+/// [`Formatter::with_unit_decimal_places`](crate::Formatter::with_unit_decimal_places).
+pub struct Ladder<'a> {
+    /// The ranged units.
+    ranged_units: &'a [RangedUnit<'a>],
+
+    /// The index of the smallest unit used.
+    min_unit: usize,
+
+    /// The index of the largest unit used, if set.
+    max_unit: Option<usize>,
+
+    /// The fraction of `range_max` from which the next unit is used.
+    promotion: f64,
+
+    /// The decimal places of each unit, overriding `DECIMAL_PLACES`.
+    decimal_places: &'a [u8],
+}
+
+impl<'a> Ladder<'a> {
+    #[inline]
+    #[must_use]
+    /// Creates a ladder using all the ranged units.
+    pub const fn new(ranged_units: &'a [RangedUnit<'a>]) -> Self {
+        Self {
+            ranged_units,
+            min_unit: 0,
+            max_unit: None,
+            promotion: 1.0,
+            decimal_places: &[],
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the ranged units.
+    pub const fn ranged_units(&self) -> &'a [RangedUnit<'a>] {
+        self.ranged_units
+    }
+
+    #[inline]
+    /// Sets the index of the smallest unit used.
+    pub(crate) const fn with_min_unit(self, min_unit: usize) -> Self {
+        Self { min_unit, ..self }
+    }
+
+    #[inline]
+    /// Sets the index of the largest unit used.
+    pub(crate) const fn with_max_unit(self, max_unit: usize) -> Self {
+        Self {
+            max_unit: Some(max_unit),
+            ..self
+        }
+    }
+
+    #[inline]
+    /// Sets the fraction of `range_max` from which the next unit is used.
+    pub(crate) const fn with_promotion(self, promotion: f64) -> Self {
+        Self { promotion, ..self }
+    }
+
+    #[inline]
+    /// Sets the decimal places of each unit.
+    pub(crate) const fn with_decimal_places(self, decimal_places: &'a [u8]) -> Self {
+        Self { decimal_places, ..self }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Returns the index of the unit for a finite, non-negative number,
+    /// within the sub-range.
+    pub(crate) fn unit_index(&self, magnitude: f64) -> usize {
+        let idx = self
+            .ranged_units
+            .iter()
+            .enumerate()
+            .position(|(idx, ranged_unit)| Target::Double(magnitude).below(&ranged_unit.range_max, self.promotion(idx)))
+            .unwrap_or(self.ranged_units.len().saturating_sub(1));
+
+        self.clamp(idx)
+    }
+
+    #[inline]
+    /// Selects the unit for a finite, non-negative `f64`, like
+    /// [`UnitSystem::select`] but const.
+    pub(crate) const fn select_f64(&self, magnitude: f64) -> Selection<'a> {
+        self.select_by(Target::Double(magnitude))
+    }
+
+    #[inline]
+    /// Selects the unit for an integer, like [`UnitSystem::select_uint`] but
+    /// const.
+    pub(crate) const fn select_u128(&self, magnitude: u128) -> Selection<'a> {
+        self.select_by(Target::Uint(magnitude))
+    }
+
+    /// Returns the unit at `idx`, divided by the previous `range_max`.
+    pub(crate) const fn selection(&self, idx: usize) -> Option<Selection<'a>> {
+        if idx >= self.ranged_units.len() {
+            return None;
+        }
+
+        let divisor = match idx.checked_sub(1) {
+            Some(prev) => self.ranged_units[prev].range_max,
+            None => Magnitude::ONE,
+        };
+
+        Some(Selection::Unit {
+            divisor,
+            ranged_unit: self.ranged_units[idx],
+            decimal_places: if idx < self.decimal_places.len() {
+                Some(self.decimal_places[idx])
+            } else {
+                None
+            },
+        })
+    }
+
+    #[inline]
+    /// Returns the promotion factor of the unit at `idx`, `1.0` for the last
+    /// unit as there is no unit to promote into.
+    const fn promotion(&self, idx: usize) -> f64 {
+        if idx + 1 < self.ranged_units.len() {
+            self.promotion
+        } else {
+            1.0
+        }
+    }
+
+    #[inline]
+    /// Clamps the index of a unit within the sub-range, where the maximum wins.
+    const fn clamp(&self, idx: usize) -> usize {
+        let idx = if idx < self.min_unit { self.min_unit } else { idx };
+
+        match self.max_unit {
+            Some(max_unit) if max_unit < idx => max_unit,
+            _ => idx,
+        }
+    }
+
+    /// Selects the first ranged unit whose `range_max`, scaled by the
+    /// promotion factor, is not reached, within the sub-range.
+    const fn select_by(&self, target: Target) -> Selection<'a> {
+        let mut idx = 0;
+
+        while idx < self.ranged_units.len() {
+            if target.below(&self.ranged_units[idx].range_max, self.promotion(idx)) {
+                break;
+            }
+
+            idx += 1;
+        }
+
+        let idx = if idx < self.ranged_units.len() {
+            self.clamp(idx)
+        } else if self.max_unit.is_some() {
+            // Grows the mantissa of the maximum unit.
+            self.clamp(usize::MAX)
+        } else {
+            return match self.selection(idx.wrapping_sub(1)) {
+                Some(Selection::Unit {
+                    divisor,
+                    ranged_unit,
+                    decimal_places,
+                }) => Selection::Overflow {
+                    divisor,
+                    ranged_unit,
+                    decimal_places,
+                },
+                _ => select_ranged(self.ranged_units, target),
+            };
+        };
+
+        match self.selection(idx) {
+            Some(selection) => selection,
+            None => select_ranged(self.ranged_units, target),
+        }
+    }
+}
+
+impl<'a> UnitSystem<'a> for Ladder<'a> {
+    #[inline]
+    fn select(&self, magnitude: f64, _positive: bool) -> Selection<'a> {
+        self.select_f64(magnitude)
+    }
+
+    #[inline]
+    fn select_uint(&self, magnitude: u128, _positive: bool) -> Selection<'a> {
+        self.select_u128(magnitude)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// A unit system always selecting the same unit, e.g. for the rows of a table
+/// column.
+///
+/// See [`Formatter::pin_unit`](crate::Formatter::pin_unit) and
+/// [`Formatter::pin`](crate::Formatter::pin).
+pub struct Pinned<'a> {
+    /// The selection for any number.
+    selection: Selection<'a>,
+}
+
+impl<'a> Pinned<'a> {
+    #[inline]
+    #[must_use]
+    /// Creates a unit system always returning `selection`.
+    pub const fn new(selection: Selection<'a>) -> Self {
+        Self { selection }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the selection for any number.
+    pub const fn selection(&self) -> Selection<'a> {
+        self.selection
+    }
+}
+
+impl<'a> UnitSystem<'a> for Pinned<'a> {
+    #[inline]
+    fn select(&self, _magnitude: f64, _positive: bool) -> Selection<'a> {
+        self.selection
+    }
+
+    #[inline]
+    fn select_uint(&self, _magnitude: u128, _positive: bool) -> Selection<'a> {
+        self.selection
+    }
+}
+
+#[derive(Clone, Copy)]
+/// A number to select the unit of.
+enum Target {
+    /// An integer, compared exactly.
+    Uint(u128),
+
+    /// A finite, non-negative `f64`.
+    Double(f64),
+}
+
+impl Target {
+    #[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+    #[inline]
+    /// Whether the number is below `range_max` scaled by `promotion`.
+    const fn below(self, range_max: &Magnitude, promotion: f64) -> bool {
+        match self {
+            // Exact without promotion, or like the `f64` path.
+            Self::Uint(magnitude) if promotion == 1.0 => range_max.exceeds(magnitude),
+            Self::Uint(magnitude) => (magnitude as f64) < range_max.to_f64() * promotion,
+            Self::Double(magnitude) => magnitude < range_max.to_f64() * promotion,
+        }
+    }
+}
+
+/// Selects the first ranged unit whose `range_max` is not reached, divided by
+/// the previous `range_max`.
+const fn select_ranged<'a>(ranged_units: &[RangedUnit<'a>], target: Target) -> Selection<'a> {
+    let mut divisor = Magnitude::ONE;
+    let mut idx = 0;
+
+    while idx < ranged_units.len() {
+        let ranged_unit = ranged_units[idx];
+
+        if target.below(&ranged_unit.range_max, 1.0) {
+            return Selection::Unit {
+                divisor,
+                ranged_unit,
+                decimal_places: None,
+            };
+        }
+
+        if idx + 1 == ranged_units.len() {
+            return Selection::Overflow {
+                divisor,
+                ranged_unit,
+                decimal_places: None,
+            };
+        }
+
+        divisor = ranged_unit.range_max;
+        idx += 1;
+    }
+
+    // No ranged units, which `Formatter::custom` rejects.
+    Selection::Overflow {
+        divisor,
+        ranged_unit: RangedUnit {
+            range_max: Magnitude::ONE,
+            unit: None,
+        },
+        decimal_places: None,
+    }
 }
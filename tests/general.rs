@@ -180,7 +180,23 @@ fn test_si() {
         999_009_999_999_999_999_999_999_999_i128 => "999.01 Y",
         -999_009_999_999_999_999_999_999_999_i128 => "-999.01 Y",
         999_999_999_999_999_999_999_999_999_i128 => "999.99 Y",
-        -999_999_999_999_999_999_999_999_999_i128 => "-999.99 Y"
+        -999_999_999_999_999_999_999_999_999_i128 => "-999.99 Y",
+
+        // R
+        1_000_000_000_000_000_000_000_000_000_i128 => "1.00 R",
+        -1_000_000_000_000_000_000_000_000_000_i128 => "-1.00 R",
+        999_999_999_999_999_999_999_999_999_999_i128 => "999.99 R",
+        -999_999_999_999_999_999_999_999_999_999_i128 => "-999.99 R",
+
+        // Q
+        1_000_000_000_000_000_000_000_000_000_000_i128 => "1.00 Q",
+        -1_000_000_000_000_000_000_000_000_000_000_i128 => "-1.00 Q",
+        999_999_999_999_999_999_999_999_999_999_999_i128 => "999.99 Q",
+        -999_999_999_999_999_999_999_999_999_999_999_i128 => "-999.99 Q",
+        1e27 => "1.00 R",
+        1e30 => "1.00 Q",
+        9.99e32 => "999.00 Q",
+        1e33 => "1e33"
     }
 }
 
@@ -306,3 +322,1421 @@ fn test_chinese() {
         -9999_9900_0000_0000_0000_i128 => "-9999.99 京"
     }
 }
+
+#[test]
+fn test_markers() {
+    use humat::format::Markers;
+
+    test_formatter! {
+        Formatter::SI.with_markers(Markers::DEFAULT);
+        0 => "0",
+        999 => "999",
+        1_000 => "1.00 K",
+        1_010 => "1.01 K",
+        1_234 => "~1.23 K",
+        -1_234 => "~-1.23 K",
+        99_999_999_999_999_999_u64 => "~100.00 P",
+        0.0 => "0.00",
+        1.5 => "1.50",
+        1.2345 => "~1.23",
+        0.001 => "<0.01",
+        -0.001 => ">-0.01",
+        1e33 => ">999.99 Q",
+        u128::MAX => ">999.99 Q",
+        -1_000_000_000_000_000_000_000_000_000_000_000_i128 => "<-999.99 Q"
+    }
+
+    test_formatter! {
+        Formatter::BINARY.with_markers(Markers {
+            approximate: "≈",
+            ..Markers::DEFAULT
+        });
+        1_536 => "1.50 Ki",
+        1_000 => "1000",
+        1_025 => "≈1.00 Ki"
+    }
+
+    assert_eq!(
+        Formatter::SI
+            .with_markers(Markers::DEFAULT)
+            .format_fixed_dp::<0>(1e33)
+            .to_string(),
+        ">999 Q"
+    );
+}
+
+#[test]
+fn test_overflow() {
+    use humat::format::{Markers, Overflow};
+
+    const TARGET: u128 = 1_234_000_000_000_000_000_000_000_000_000_000;
+
+    test_formatter! {
+        Formatter::SI;
+        TARGET => "1.234e33",
+        1.234e33 => "1.234e33"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_overflow(Overflow::Extend);
+        TARGET => "1234.00 Q",
+        -i128::try_from(TARGET).unwrap() => "-1234.00 Q",
+        1.234e33 => "1234.00 Q",
+        999_999_999_999_999_999_999_999_999_999_999_u128 => "999.99 Q",
+        // In fixed notation, to the precision of `f64`
+        1e36 => "1000000.00 Q",
+        1e60 => "1000000000000000000000000000000.00 Q"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_overflow(Overflow::Scientific);
+        TARGET => "1.23e33",
+        -i128::try_from(TARGET).unwrap() => "-1.23e33",
+        1e300 => "1.00e300",
+        1.234e33 => "1.23e33"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_overflow(Overflow::Engineering);
+        TARGET => "1.23e33",
+        TARGET * 10 => "12.34e33",
+        TARGET * 100 => "123.40e33",
+        1e300 => "1.00e300"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_overflow(Overflow::Scientific).with_markers(Markers::DEFAULT);
+        TARGET => "~1.23e33",
+        TARGET / 1_234 * 1_200 => "1.20e33"
+    }
+
+    let formatter = Formatter::SI.with_overflow(Overflow::Error);
+
+    assert_eq!(
+        formatter.try_format(1_000).map(|f| f.to_string()),
+        Ok("1.00 K".to_owned())
+    );
+    assert_eq!(formatter.try_format(TARGET).err().map(|e| e.number()), Some(1.234e33));
+    assert_eq!(
+        formatter
+            .try_format(-i128::try_from(TARGET).unwrap())
+            .err()
+            .map(|e| e.number()),
+        Some(-1.234e33)
+    );
+    assert_eq!(formatter.format(TARGET).to_string(), "1.234e33");
+}
+
+#[test]
+fn test_notation() {
+    use humat::format::{ExponentStyle, Notation, Overflow};
+
+    test_formatter! {
+        Formatter::SI.with_notation(Notation::Scientific);
+        0 => "0.00e0",
+        1 => "1.00e0",
+        -1_234 => "-1.23e3",
+        1_234_567_890 => "1.23e9",
+        0.000_123 => "1.23e-4",
+        -0.5 => "-5.00e-1",
+        1e300 => "1.00e300"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_notation(Notation::Engineering);
+        1 => "1.00e0",
+        12_345 => "12.34e3",
+        -123_456 => "-123.45e3",
+        1_234_567 => "1.23e6",
+        0.000_123 => "123.00e-6",
+        0.001_23 => "1.23e-3"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_notation(Notation::Engineering).with_exponent_style(ExponentStyle::Uppercase);
+        12_345 => "12.34E+03",
+        0.000_123 => "123.00E-06",
+        1e300 => "1.00E+300"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_notation(Notation::Scientific).with_exponent_style(ExponentStyle::Superscript);
+        1_234_567_890 => "1.23×10⁹",
+        0.000_123 => "1.23×10⁻⁴",
+        1e300 => "1.00×10³⁰⁰"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_notation(Notation::Scientific).with_exponent_style(ExponentStyle::Latex);
+        1_234_567_890 => r"1.23 \times 10^{9}",
+        0.000_123 => r"1.23 \times 10^{-4}"
+    }
+
+    test_formatter! {
+        Formatter::SI
+            .with_overflow(Overflow::Engineering)
+            .with_exponent_style(ExponentStyle::Superscript)
+            .with_custom_unit("B");
+        1_234 => "1.23 KB",
+        1.234e34 => "12.34×10³³ B"
+    }
+}
+
+#[test]
+fn test_beyond_u128() {
+    test_formatter! {
+        Formatter::CHINESE;
+        u128::MAX => "340.28 涧",
+        1e36 => "1.00 涧",
+        1e40 => "1.00 正",
+        1.5e44 => "1.49 载", // f64 limitation
+        1e48 => "1.00 极",
+        9.5e51 => "9500.00 极",
+        1e52 => "1e52"
+    }
+
+    let formatter = humat::ladder!(base = 1_000_000_000_000_000, units = [_, "A", "B", "C"]);
+
+    test_formatter! {
+        formatter;
+        1_000_000_000_000_000_u64 => "1.00 A",
+        u128::MAX => "340282366.92 B",
+        1e45 => "1.00 C",
+        2.5e59 => "250000000000000.00 C",
+        1e60 => "1e60"
+    }
+}
+
+#[test]
+fn test_negative_double() {
+    use humat::format::{Markers, Notation, Overflow};
+
+    test_formatter! {
+        Formatter::SI;
+        -0.0 => "-0.00",
+        0.0 => "0.00",
+        -1.5 => "-1.50",
+        -999.0 => "-999.00",
+        -1_000.0 => "-1.00 K",
+        -5_000.0 => "-5.00 K",
+        -1_234_567.0 => "-1.23 M",
+        -1e30 => "-1.00 Q",
+        -1e33 => "-1e33",
+        -0.0_f32 => "-0.00",
+        -5_000.0_f32 => "-5.00 K",
+        -1.5e9_f32 => "-1.50 G"
+    }
+
+    // Scaled the same as the signed integers
+    #[allow(clippy::cast_precision_loss, reason = "exact below 2^53")]
+    for target in [-1_000_i64, -5_000, -99_999, -1_234_567, -1_000_000_000_000] {
+        assert_eq!(
+            Formatter::SI.format(target as f64).to_string(),
+            Formatter::SI.format(target).to_string()
+        );
+    }
+
+    test_formatter! {
+        Formatter::BINARY;
+        -1_536.0 => "-1.50 Ki",
+        -1_048_576.0 => "-1.00 Mi"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_markers(Markers::DEFAULT);
+        -0.0 => "-0.00",
+        -0.001 => ">-0.01",
+        -1_234.0 => "~-1.23 K",
+        -1e33 => "<-999.99 Q"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_overflow(Overflow::Extend);
+        -1.5e33 => "-1500.00 Q"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_notation(Notation::Scientific);
+        -0.0 => "-0.00e0",
+        -5_000.0 => "-5.00e3"
+    }
+}
+
+#[test]
+fn test_placeholders() {
+    use humat::format::Placeholders;
+
+    test_formatter! {
+        Formatter::SI.with_custom_unit("B");
+        f64::NAN => "NaN B",
+        // NaN with the sign bit set, e.g. from `0.0 / 0.0`
+        -f64::NAN => "NaN B",
+        f64::INFINITY => "inf B",
+        f64::NEG_INFINITY => "-inf B",
+        f32::NAN => "NaN B",
+        -0.0 => "-0.00 B",
+        Some(1_000) => "1.00 KB",
+        Some(-1.5) => "-1.50 B",
+        None::<i32> => ""
+    }
+
+    test_formatter! {
+        Formatter::SI.with_custom_unit("B").with_placeholders(Placeholders {
+            nan: Some("-"),
+            infinity: Some("∞"),
+            neg_infinity: Some("-∞"),
+            neg_zero: Some("0"),
+            missing: "—",
+        });
+        f64::NAN => "-",
+        f64::INFINITY => "∞",
+        f64::NEG_INFINITY => "-∞",
+        f32::NEG_INFINITY => "-∞",
+        -0.0 => "0",
+        -0.0_f32 => "0",
+        0.0 => "0.00 B",
+        -0.001 => "-0.00 B",
+        None::<u64> => "—",
+        Some(f64::NAN) => "-",
+        Some(Some(2_048_u64)) => "2.04 KB"
+    }
+
+    assert!(Formatter::SI.format(None::<f64>).number().is_nan());
+    assert_eq!(Formatter::SI.format_fixed_dp::<1>(None::<f64>).to_string(), "");
+}
+
+#[test]
+fn test_sign_style() {
+    use humat::format::{Markers, Notation, SignStyle};
+
+    test_formatter! {
+        Formatter::SI.with_sign_style(SignStyle::MINUS);
+        -1 => "−1",
+        -1_200 => "−1.20 K",
+        -1_200.0 => "−1.20 K",
+        -0.0 => "−0.00",
+        -f64::NAN => "NaN",
+        1_200 => "1.20 K"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_sign_style(SignStyle::PARENTHESES);
+        -f64::NAN => "NaN",
+        f64::NEG_INFINITY => "(inf)"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_sign_style(SignStyle::PARENTHESES).with_custom_unit("B");
+        -1 => "(1 B)",
+        -1_200 => "(1.20 KB)",
+        -1_200.0 => "(1.20 KB)",
+        1_200 => "1.20 KB"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_sign_style(SignStyle::PLUS);
+        0 => "+0",
+        1 => "+1",
+        -1 => "-1",
+        1_200 => "+1.20 K",
+        -1_200.0 => "-1.20 K"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_sign_style(SignStyle {
+            positive: "+",
+            ..SignStyle::MINUS
+        });
+        1_200 => "+1.20 K",
+        -1_200 => "−1.20 K"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_sign_style(SignStyle::PARENTHESES).with_markers(Markers::DEFAULT);
+        -1_234 => "~(1.23 K)",
+        -0.001 => ">(0.01)",
+        -1e33 => "<(999.99 Q)"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_sign_style(SignStyle::MINUS).with_notation(Notation::Scientific);
+        -1_234 => "−1.23e3",
+        -0.000_123 => "−1.23e-4"
+    }
+}
+
+#[test]
+fn test_locale() {
+    use humat::format::{Locale, Markers, Notation};
+
+    test_formatter! {
+        Formatter::CHINESE.with_locale(Locale::EN);
+        1_234 => "1,234",
+        12_345 => "1.23 万",
+        99_990_000 => "9,999.00 万",
+        -1_234.5 => "-1,234.50"
+    }
+
+    test_formatter! {
+        Formatter::CHINESE.with_locale(Locale::DE);
+        1_234 => "1.234",
+        1_234.5 => "1.234,50",
+        12_345_678.9 => "1.234,56 万"
+    }
+
+    test_formatter! {
+        Formatter::CHINESE.with_locale(Locale::FR);
+        1_234 => "1\u{202f}234",
+        1_234.5 => "1\u{202f}234,50"
+    }
+
+    test_formatter! {
+        Formatter::CHINESE.with_locale(Locale::CH);
+        1_234 => "1\u{2019}234",
+        1_234.5 => "1\u{2019}234.50"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_locale(Locale::DE).with_markers(Markers::DEFAULT);
+        0.001 => "<0,01",
+        1e33 => ">999,99 Q",
+        1_234 => "~1,23 K"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_locale(Locale::DE).with_notation(Notation::Scientific);
+        1_234 => "1,23e3"
+    }
+
+    test_formatter! {
+        humat::ladder!(base = 10_000_000, units = [_, "Cr"]).with_locale(Locale::IN);
+        123 => "123",
+        1_234 => "1,234",
+        1_234_567 => "12,34,567",
+        1_234_567.5 => "12,34,567.50",
+        1_234_567_890 => "123.45 Cr",
+        12_345_670_000_000_u64 => "12,34,567.00 Cr"
+    }
+
+    let formatted = Formatter::CHINESE
+        .with_locale(Locale::EN)
+        .format_fixed_dp::<0>(12_345.0);
+    assert_eq!(formatted.to_string(), "1 万");
+}
+
+#[test]
+fn test_localized_units() {
+    use humat::format::Locale;
+
+    test_formatter! {
+        Formatter::GERMAN;
+        999 => "999",
+        1_500 => "1,50 Tsd",
+        1_200_000 => "1,20 Mio",
+        3_400_000_000_u64 => "3,40 Mrd",
+        -1_200_000.0 => "-1,20 Mio",
+        1_234_000_000_000_000_u64 => "1.234.000.000.000.000,00"
+    }
+
+    test_formatter! {
+        Formatter::FRENCH;
+        1_500 => "1,50 k",
+        1_200_000 => "1,20 M",
+        3_400_000_000_u64 => "3,40 Md",
+        5_600_000_000_000_u64 => "5,60 Bn"
+    }
+
+    test_formatter! {
+        Formatter::SPANISH;
+        1_500 => "1,50 mil",
+        3_400_000_000_u64 => "3,40 mil M",
+        5_600_000_000_000_u64 => "5,60 B"
+    }
+
+    test_formatter! {
+        Formatter::RUSSIAN;
+        1_500 => "1,50 тыс.",
+        2_300_000 => "2,30 млн",
+        3_400_000_000_u64 => "3,40 млрд",
+        5_600_000_000_000_u64 => "5,60 трлн"
+    }
+
+    test_formatter! {
+        Formatter::PORTUGUESE;
+        1_500 => "1,50 mil",
+        2_300_000 => "2,30 mi",
+        3_400_000_000_u64 => "3,40 bi"
+    }
+
+    test_formatter! {
+        Formatter::ITALIAN;
+        1_500 => "1,50 mila",
+        2_300_000 => "2,30 Mln",
+        3_400_000_000_u64 => "3,40 Mrd"
+    }
+
+    test_formatter! {
+        Formatter::RUSSIAN.with_locale(Locale::DEFAULT);
+        2_300_000 => "2.30 млн"
+    }
+}
+
+#[cfg(feature = "cldr")]
+#[test]
+fn test_cldr() {
+    use humat::format::{cldr, Markers, Overflow};
+
+    test_formatter! {
+        cldr::EN_SHORT;
+        999 => "999",
+        1_200 => "1.20K",
+        1_200_000 => "1.20M",
+        3_400_000_000_u64 => "3.40B",
+        1_500_000_000_000_000_u64 => "1500.00T",
+        -1_200.0 => "-1.20K"
+    }
+
+    test_formatter! {
+        cldr::EN_LONG;
+        1_200 => "1.20 thousand",
+        3_400_000_000_u64 => "3.40 billion"
+    }
+
+    test_formatter! {
+        cldr::DE_SHORT;
+        1_234 => "1234",
+        123_456 => "123.456",
+        1_200_000 => "1,20\u{a0}Mio.",
+        3_400_000_000_u64 => "3,40\u{a0}Mrd.",
+        5_600_000_000_000_u64 => "5,60\u{a0}Bio."
+    }
+
+    test_formatter! {
+        cldr::DE_LONG;
+        1_234 => "1,23 Tausend",
+        1_200_000 => "1,20 Millionen"
+    }
+
+    test_formatter! {
+        cldr::FR_SHORT;
+        1_234 => "1,23\u{a0}k",
+        3_400_000_000_u64 => "3,40\u{a0}Md"
+    }
+
+    test_formatter! {
+        cldr::JA_SHORT;
+        1_234 => "1234",
+        12_345 => "1.23万",
+        123_456_789 => "1.23億"
+    }
+
+    test_formatter! {
+        cldr::ZH_SHORT;
+        12_345 => "1.23万",
+        1_234_500_000_000_u64 => "1.23万亿"
+    }
+
+    test_formatter! {
+        cldr::DE_SHORT.with_markers(Markers::DEFAULT).with_overflow(Overflow::Unscaled);
+        1e18 => ">999,99\u{a0}Bio."
+    }
+}
+
+#[test]
+fn test_digit_set() {
+    use humat::format::{DigitSet, Locale, Markers, Notation, SignStyle};
+
+    test_formatter! {
+        Formatter::SI.with_digit_set(DigitSet::ARABIC_INDIC);
+        7 => "٧",
+        1_500 => "١٫٥٠ K",
+        1_500.0 => "١٫٥٠ K",
+        -1_500 => "\u{61c}-١٫٥٠ K",
+        f64::NAN => "NaN"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_digit_set(DigitSet::EXTENDED_ARABIC_INDIC);
+        1_500 => "۱٫۵۰ K"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_digit_set(DigitSet::DEVANAGARI).with_custom_unit("B2");
+        1_500 => "१.५० KB2"
+    }
+
+    test_formatter! {
+        Formatter::CHINESE.with_separator("").with_digit_set(DigitSet::FULLWIDTH);
+        15_000 => "１．５０万",
+        -15_000.0 => "－１．５０万"
+    }
+
+    test_formatter! {
+        Formatter::CHINESE.with_locale(Locale::EN).with_digit_set(DigitSet::FULLWIDTH);
+        1_234 => "１,２３４"
+    }
+
+    // Group separators are not decimal marks.
+    test_formatter! {
+        Formatter::CHINESE.with_locale(Locale::DE).with_digit_set(DigitSet::ARABIC_INDIC);
+        9_999 => "٩.٩٩٩",
+        15_000 => "١,٥٠ 万"
+    }
+
+    test_formatter! {
+        Formatter::SI
+            .with_digit_set(DigitSet::DEVANAGARI)
+            .with_markers(Markers::DEFAULT)
+            .with_sign_style(SignStyle::PARENTHESES);
+        -1_234 => "~(१.२३ K)"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_digit_set(DigitSet::DEVANAGARI).with_notation(Notation::Scientific);
+        0.000_123 => "१.२३e-४"
+    }
+}
+
+#[test]
+fn test_bidi() {
+    use humat::format::{Bidi, DigitSet, Markers, Placement};
+
+    test_formatter! {
+        Formatter::BINARY.with_custom_unit("B").with_bidi(Bidi::Isolate);
+        1 => "\u{2068}1 B\u{2069}",
+        1_536 => "\u{2068}1.50 KiB\u{2069}",
+        f64::NAN => "\u{2068}NaN B\u{2069}"
+    }
+
+    test_formatter! {
+        Formatter::BINARY.with_bidi(Bidi::LeftToRightIsolate).with_markers(Markers::DEFAULT);
+        1_537 => "\u{2066}~1.50 Ki\u{2069}",
+        1 => "\u{2066}1\u{2069}"
+    }
+
+    test_formatter! {
+        Formatter::SI
+            .with_digit_set(DigitSet::ARABIC_INDIC)
+            .with_bidi(Bidi::RightToLeftIsolate);
+        1_500 => "\u{2067}١٫٥٠ K\u{2069}"
+    }
+
+    test_formatter! {
+        Formatter::BINARY.with_bidi(Bidi::LeftToRightMark);
+        1 => "1",
+        1_536 => "1.50\u{200e} Ki\u{200e}"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_separator("").with_bidi(Bidi::RightToLeftMark);
+        1_500 => "1.50\u{200f}K\u{200f}"
+    }
+
+    // Marks around right-to-left units, on either side of the number
+    let hebrew = humat::ladder!(base = 1_000, units = [_, "אלף", "מיליון"]).with_bidi(Bidi::RightToLeftMark);
+
+    test_formatter! {
+        hebrew;
+        12 => "12",
+        1_200 => "1.20\u{200f} אלף\u{200f}",
+        -1_200 => "-1.20\u{200f} אלף\u{200f}"
+    }
+
+    test_formatter! {
+        hebrew.with_placement(Placement::UNITS_BEFORE);
+        12 => "12",
+        1_200 => "\u{200f}אלף \u{200f}1.20",
+        -1_200 => "-\u{200f}אלף \u{200f}1.20",
+        1_500_000 => "\u{200f}מיליון \u{200f}1.50"
+    }
+
+    test_formatter! {
+        humat::ladder!(base = 1_000, units = [_, "ألف"])
+            .with_custom_unit("م")
+            .with_digit_set(DigitSet::ARABIC_INDIC)
+            .with_placement(Placement::UNITS_BEFORE)
+            .with_bidi(Bidi::RightToLeftMark);
+        12 => "\u{200f}م \u{200f}١٢",
+        1_500 => "\u{200f}ألفم \u{200f}١٫٥٠"
+    }
+}
+
+#[test]
+fn test_placement() {
+    use humat::format::{Markers, Placement, SignStyle, UnitPosition};
+
+    test_formatter! {
+        Formatter::SI.with_separator("").with_placement(Placement {
+            prefix: "$",
+            ..Placement::DEFAULT
+        });
+        12 => "$12",
+        1_200_000 => "$1.20M",
+        -1_200_000 => "-$1.20M",
+        -1_200_000.0 => "-$1.20M"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_placement(Placement {
+            suffix: " USD",
+            ..Placement::DEFAULT
+        });
+        12 => "12 USD",
+        1_200_000 => "1.20 M USD"
+    }
+
+    test_formatter! {
+        Formatter::CHINESE.with_separator("").with_placement(Placement {
+            prefix: "¥",
+            ..Placement::DEFAULT
+        });
+        35_000 => "¥3.50万"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_custom_unit("B").with_placement(Placement {
+            prefix: "≈",
+            suffix: "/s",
+            unit_position: UnitPosition::Before,
+        });
+        12 => "≈B 12/s",
+        1_200 => "≈KB 1.20/s"
+    }
+
+    test_formatter! {
+        Formatter::SI
+            .with_separator("")
+            .with_markers(Markers::DEFAULT)
+            .with_sign_style(SignStyle::PARENTHESES)
+            .with_placement(Placement {
+                prefix: "$",
+                ..Placement::DEFAULT
+            });
+        -1_234_000 => "~($1.23M)"
+    }
+}
+
+#[test]
+fn test_money() {
+    use humat::format::{Currency, Locale, Markers, Placement};
+
+    let formatter = Formatter::SI.with_separator("").with_locale(Locale::EN);
+
+    macro_rules! test_money {
+        ($formatter:expr; $($minor_units:expr, $currency:expr => $ideal:expr),*) => {
+            $(assert_eq!($formatter.format_money($minor_units, $currency).to_string(), $ideal);)*
+        };
+    }
+
+    test_money! {
+        formatter;
+        0, Currency::USD => "$0.00",
+        5, Currency::USD => "$0.05",
+        -5, Currency::USD => "-$0.05",
+        99_999, Currency::USD => "$999.99",
+        123_450, Currency::USD => "$1.23K",
+        120_000_000, Currency::USD => "$1.20M",
+        -120_000_000, Currency::USD => "-$1.20M",
+        999, Currency::JPY => "¥999",
+        1_234, Currency::JPY => "¥1.23K",
+        12_345, Currency::KWD => "KWD\u{a0}12.345",
+        // Not rounded through `f64`
+        999_999_999_999_999_999, Currency::USD => "$9.99P"
+    }
+
+    let usd = Currency::USD.with_compact_threshold(1_000_000);
+
+    test_money! {
+        formatter;
+        12_345_678, usd => "$123,456.78",
+        99_999_999, usd => "$999,999.99",
+        100_000_000, usd => "$1.00M"
+    }
+
+    let eur = Currency::EUR.with_compact_threshold(1_000_000);
+
+    test_money! {
+        Formatter::GERMAN;
+        340_000_000, eur => "€3,40 Mio",
+        123_456, eur => "€1.234,56"
+    }
+
+    test_money! {
+        Formatter::SI.with_placement(Placement {
+            prefix: "US$",
+            ..Placement::DEFAULT
+        });
+        120_000_000, Currency::USD => "US$1.20 M"
+    }
+
+    test_money! {
+        Formatter::SI.with_separator("").with_markers(Markers::DEFAULT);
+        123_456, Currency::USD => "~$1.23K",
+        123_000, Currency::USD => "$1.23K",
+        12_345, Currency::USD => "$123.45"
+    }
+
+    assert_eq!(Currency::from_code("JPY"), Some(Currency::JPY));
+    assert_eq!(Currency::from_code("XXX"), None);
+    assert!((Formatter::SI.format_money(-123_450, Currency::USD).number() + 1.2345).abs() < 1e-12);
+}
+
+#[test]
+fn test_money_overflow() {
+    use humat::format::{Currency, Markers, Notation, Overflow};
+
+    let formatter = humat::ladder!(base = 1_000, units = [_, "K"]).with_separator("");
+
+    // Beyond the last unit, the cents are kept.
+    for (formatter, ideal) in [
+        (formatter, "$123456789.01"),
+        (formatter.with_overflow(Overflow::Extend), "$123456.78K"),
+        (formatter.with_overflow(Overflow::Scientific), "$1.23e8"),
+        (formatter.with_markers(Markers::DEFAULT), ">$999.99K"),
+    ] {
+        assert_eq!(formatter.format_money(12_345_678_901, Currency::USD).to_string(), ideal);
+    }
+
+    // The mantissa is computed from the integer amount, without `f64`.
+    let formatter = Formatter::SI.with_separator("").with_notation(Notation::Scientific);
+
+    assert_eq!(
+        formatter
+            .format_money_fixed_dp::<20>(1_000_000_000_000_000_001, Currency::USD)
+            .to_string(),
+        "$1.00000000000000000100e16"
+    );
+    assert_eq!(
+        formatter
+            .with_markers(Markers::DEFAULT)
+            .format_money(-123_456, Currency::USD)
+            .to_string(),
+        "~-$1.23e3"
+    );
+    assert_eq!(
+        formatter
+            .with_notation(Notation::Engineering)
+            .format_money(12_345_678, Currency::JPY)
+            .to_string(),
+        "¥12.34e6"
+    );
+}
+
+#[test]
+fn test_money_fraction_digits() {
+    use humat::format::{Currency, Locale};
+
+    let formatter = Formatter::SI.with_separator("").with_locale(Locale::EN);
+
+    // Minor units beyond `u128` precision do not overflow.
+    for (minor_units, fraction_digits, ideal) in [
+        (i128::MAX, 37, "X17.0141183460469231731687303715884105727"),
+        (i128::MAX, 38, "X1.70"),
+        (-12_345, 40, "-X0.00"),
+        (i128::MIN, u8::MAX, "-X0.00"),
+    ] {
+        let currency = Currency::new("XXX", "X", fraction_digits);
+
+        assert_eq!(formatter.format_money(minor_units, currency).to_string(), ideal);
+    }
+}
+
+#[test]
+fn test_borrowed_units() {
+    use core::num::NonZeroU128;
+
+    use humat::format::{Locale, Placeholders, Placement};
+    use humat::unit::RangedUnit;
+
+    // e.g. loaded from a translation file at runtime
+    let config = String::from("Tsd.,Mio.,Mrd.");
+    let separator = String::from("\u{a0}");
+    let custom_unit = String::from("€");
+
+    let mut units = config.split(',');
+    let ranged_units = RangedUnit::geometric(
+        NonZeroU128::new(1_000).unwrap(),
+        [None, units.next(), units.next(), units.next()],
+    );
+
+    let formatter = Formatter::custom(&ranged_units)
+        .unwrap()
+        .with_separator(&separator)
+        .with_custom_unit(&custom_unit);
+
+    test_formatter! {
+        formatter;
+        12 => "12\u{a0}€",
+        1_500 => "1.50\u{a0}Tsd.€",
+        2_300_000 => "2.30\u{a0}Mio.€",
+        3_400_000_000_u64 => "3.40\u{a0}Mrd.€"
+    }
+
+    assert_eq!(formatter.format(1_500).separator(), "\u{a0}");
+    assert_eq!(formatter.format(1_500).custom_unit(), Some("€"));
+    // The other options are borrowed alike, e.g. translated placeholders.
+    let missing = String::from("k. A.");
+    let suffix = String::from(" netto");
+    let decimal_mark = String::from(",");
+
+    test_formatter! {
+        formatter
+            .with_placeholders(Placeholders {
+                missing: &missing,
+                ..Placeholders::DEFAULT
+            })
+            .with_placement(Placement {
+                suffix: &suffix,
+                ..Placement::DEFAULT
+            })
+            .with_locale(Locale {
+                decimal_mark: &decimal_mark,
+                ..Locale::DEFAULT
+            });
+        None::<u64> => "k. A.",
+        1_500 => "1,50\u{a0}Tsd.€ netto"
+    }
+}
+
+#[test]
+fn test_heterogeneous_units() {
+    struct Column {
+        formatter: Formatter<'static>,
+    }
+
+    let columns = [
+        Column {
+            formatter: Formatter::SI,
+        },
+        Column {
+            formatter: humat::ladder!(base = 60, units = ["s", "min"]),
+        },
+    ];
+
+    let formatted: Vec<_> = columns
+        .iter()
+        .map(|column| column.formatter.format(90).to_string())
+        .collect();
+
+    assert_eq!(formatted, ["90", "1.50 min"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_formatter_builder() {
+    use humat::format::{BuildError, FormatterBuilder, FormatterOptions, Locale, Overflow, OwnedFormatter};
+    use humat::unit::LadderError;
+
+    fn units(units: &[&str]) -> Vec<String> {
+        units.iter().map(|&unit| String::from(unit)).collect()
+    }
+
+    // e.g. deserialized from a per-tenant configuration, and stored per tenant
+    let load = |units: Vec<String>, options| {
+        FormatterBuilder::from_base(10_000)
+            .with_units(units)
+            .with_options(options)
+            .build()
+    };
+
+    let tenants: std::collections::HashMap<&str, OwnedFormatter> = [
+        ("cn", load(units(&["", "万", "亿"]), FormatterOptions::DEFAULT).unwrap()),
+        (
+            "en",
+            load(
+                units(&["", "万", "亿"]),
+                FormatterOptions {
+                    overflow: Overflow::Extend,
+                    locale: Locale::EN,
+                    ..FormatterOptions::DEFAULT
+                },
+            )
+            .unwrap(),
+        ),
+    ]
+    .into();
+
+    // The options are set on the builder, e.g. from the same configuration.
+    test_formatter! {
+        tenants["en"].formatter();
+        1_234_500_000_000_u64 => "12,345.00 亿"
+    }
+
+    // A clone owns its units.
+    let built = tenants["cn"].clone();
+    drop(tenants);
+
+    test_formatter! {
+        built.formatter();
+        9_999 => "9999",
+        12_345 => "1.23 万",
+        123_456_789 => "1.23 亿",
+        -123_456_789.0 => "-1.23 亿"
+    }
+
+    test_formatter! {
+        built.formatter().with_overflow(Overflow::Extend);
+        1_234_500_000_000_u64 => "12345.00 亿"
+    }
+
+    let built = FormatterBuilder::from_thresholds(vec![1_024, 1_048_576])
+        .with_units(units(&["", "Ki"]))
+        .with_separator(String::new())
+        .with_custom_unit(String::from("B"))
+        .build()
+        .unwrap();
+
+    test_formatter! {
+        built.formatter();
+        1 => "1B",
+        1_536 => "1.50KiB"
+    }
+
+    assert_eq!(
+        FormatterBuilder::from_base(1_000).build().unwrap_err(),
+        BuildError::Ladder(LadderError::Empty)
+    );
+    assert_eq!(
+        FormatterBuilder::from_base(0)
+            .with_units(units(&["", "K"]))
+            .build()
+            .unwrap_err(),
+        BuildError::Ladder(LadderError::ZeroBase)
+    );
+    assert_eq!(
+        FormatterBuilder::from_thresholds(vec![1_000, 1_000_001])
+            .with_units(units(&["", "K"]))
+            .build()
+            .unwrap_err(),
+        BuildError::Ladder(LadderError::NotPowerOfBase { index: 1 })
+    );
+    assert_eq!(
+        FormatterBuilder::from_thresholds(vec![1_000])
+            .with_units(units(&["", "K"]))
+            .build()
+            .unwrap_err(),
+        BuildError::LengthMismatch {
+            thresholds: 1,
+            units: 2
+        }
+    );
+    assert_eq!(
+        BuildError::LengthMismatch {
+            thresholds: 1,
+            units: 2
+        }
+        .to_string(),
+        "got 1 thresholds for 2 units"
+    );
+}
+
+#[test]
+fn test_ladder_error() {
+    use core::num::NonZeroU128;
+
+    use humat::unit::{LadderError, Magnitude, RangedUnit};
+
+    // Hand-written, as `RangedUnit::geometric` cannot build invalid ladders.
+    fn ranged_units(range_maxes: &[(u128, u32)]) -> Vec<RangedUnit<'static>> {
+        range_maxes
+            .iter()
+            .map(|&(base, exponent)| RangedUnit {
+                range_max: Magnitude::new(NonZeroU128::new(base).unwrap(), exponent),
+                unit: None,
+            })
+            .collect()
+    }
+
+    assert_eq!(Formatter::custom(&[]).err(), Some(LadderError::Empty));
+    assert_eq!(
+        Formatter::custom(&ranged_units(&[(1 << 64, 2)])).err(),
+        Some(LadderError::BaseOverflow)
+    );
+    assert_eq!(
+        Formatter::custom(&ranged_units(&[(1_000, 1), (1_000, 2), (1_000, 2)])).err(),
+        Some(LadderError::NonMonotonic { index: 2 })
+    );
+    assert_eq!(
+        Formatter::custom(&ranged_units(&[(1_000, 1), (1_000, 2), (1_000, 4)])).err(),
+        Some(LadderError::NotPowerOfBase { index: 2 })
+    );
+    Formatter::custom(&RangedUnit::geometric(NonZeroU128::new(1_000).unwrap(), [None; 3])).unwrap();
+
+    assert_eq!(
+        LadderError::NotPowerOfBase { index: 2 }.to_string(),
+        "a range_max is not the power of the base (ranged unit 2)"
+    );
+    assert_eq!(LadderError::Empty.to_string(), "no ranged units are given");
+}
+
+#[test]
+fn test_ladder() {
+    const BINARY: Formatter = humat::ladder!(base = 1024, units = [_, "Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"]);
+    // The typed methods of the default ladder are const.
+    const FORMATTED: humat::format::Formatted = BINARY.format_u64(1_536);
+
+    for value in [0, 1_023, 1_536, 1_048_576, 3_u128 << 80, u128::MAX] {
+        assert_eq!(
+            BINARY.format(value).to_string(),
+            Formatter::BINARY.format(value).to_string()
+        );
+    }
+
+    assert_eq!(FORMATTED.to_string(), "1.50 Ki");
+
+    test_formatter! {
+        humat::ladder!(base = 10_000, units = [_, "万", "亿",]).with_separator("");
+        9_999 => "9999",
+        12_345 => "1.23万",
+        123_456_789 => "1.23亿"
+    }
+}
+
+#[test]
+fn test_unit_system() {
+    use core::num::NonZeroU128;
+
+    use humat::format::{Markers, Overflow};
+    use humat::unit::{Magnitude, RangedUnit, Selection, UnitSystem};
+
+    /// Powers of ten up to 10^6, named by their exponent.
+    #[derive(Clone, Copy)]
+    struct Decades;
+
+    impl UnitSystem<'static> for Decades {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        fn select(&self, magnitude: f64, _positive: bool) -> Selection<'static> {
+            const UNITS: [&str; 7] = ["e0", "e1", "e2", "e3", "e4", "e5", "e6"];
+            const TEN: NonZeroU128 = NonZeroU128::new(10).unwrap();
+
+            let exponent = if magnitude < 1.0 { 0 } else { magnitude.log10() as u32 };
+            let ranged_unit = |exponent: u32| RangedUnit {
+                range_max: Magnitude::new(TEN, exponent + 1),
+                unit: Some(UNITS[exponent as usize]),
+            };
+
+            if exponent < 7 {
+                Selection::Unit {
+                    divisor: Magnitude::new(TEN, exponent),
+                    ranged_unit: ranged_unit(exponent),
+                    decimal_places: None,
+                }
+            } else {
+                Selection::Overflow {
+                    divisor: Magnitude::new(TEN, 6),
+                    ranged_unit: ranged_unit(6),
+                    decimal_places: None,
+                }
+            }
+        }
+    }
+
+    /// A last unit whose `range_max` does not exceed its divisor.
+    #[derive(Clone, Copy)]
+    struct Unchecked;
+
+    impl UnitSystem<'static> for Unchecked {
+        fn select(&self, _magnitude: f64, _positive: bool) -> Selection<'static> {
+            Selection::Overflow {
+                divisor: Magnitude::new(NonZeroU128::new(1_000).unwrap(), 1),
+                ranged_unit: RangedUnit {
+                    range_max: Magnitude::ONE,
+                    unit: Some("K"),
+                },
+                decimal_places: None,
+            }
+        }
+    }
+
+    let formatter = Formatter::SI.with_separator("").with_unit_system(Decades);
+
+    test_formatter! {
+        formatter;
+        5 => "5e0",
+        42 => "4.20e1",
+        -42 => "-4.20e1",
+        12_345 => "1.23e4",
+        1_234.5 => "1.23e3",
+        12_345_678 => "12345678.00"
+    }
+
+    test_formatter! {
+        formatter.with_overflow(Overflow::Extend);
+        12_345_678 => "12.34e6"
+    }
+
+    test_formatter! {
+        formatter.with_markers(Markers::DEFAULT);
+        12_345_678 => ">9.99e6"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_markers(Markers::DEFAULT).with_unit_system(Unchecked);
+        1_234 => "1234.00"
+    }
+
+    // Ranged units are the default unit system.
+    let units: &[RangedUnit] = &RangedUnit::geometric(NonZeroU128::new(1_000).unwrap(), [None, Some("K")]);
+    let formatter = Formatter::SI.with_unit_system(units);
+
+    for value in [999, 1_500, 999_999, 1_000_000] {
+        assert_eq!(
+            formatter.format(value).to_string(),
+            Formatter::custom(units).unwrap().format(value).to_string()
+        );
+    }
+}
+
+#[test]
+fn test_format_in_unit() {
+    use humat::format::Markers;
+
+    let formatter = Formatter::BINARY.with_custom_unit("B");
+
+    let in_gi = |value: u64| formatter.format_in_unit(value, "Gi").unwrap().to_string();
+    assert_eq!(in_gi(0), "0.00 GiB");
+    assert_eq!(in_gi(53_687_092), "0.05 GiB");
+    assert_eq!(in_gi(32_212_254_720), "30.00 GiB");
+    // Beyond the range of `Gi`
+    assert_eq!(in_gi(5_497_558_138_880), "5120.00 GiB");
+
+    assert_eq!(
+        formatter.format_in_unit(-1_572_864.0, 2).unwrap().to_string(),
+        "-1.50 MiB"
+    );
+    assert_eq!(
+        formatter.format_in_unit(1_572_864, "").unwrap().to_string(),
+        "1572864 B"
+    );
+    assert_eq!(
+        formatter
+            .format_in_unit_fixed_dp::<3>(1_572_864, "Ki")
+            .unwrap()
+            .to_string(),
+        "1536.000 KiB"
+    );
+    assert_eq!(
+        formatter
+            .with_markers(Markers::DEFAULT)
+            .format_in_unit(1_234_567, "Mi")
+            .unwrap()
+            .to_string(),
+        "~1.17 MiB"
+    );
+
+    assert!(formatter.format_in_unit(1, 9).is_none());
+    assert!(formatter.format_in_unit(1, "GB").is_none());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_pin() {
+    use humat::format::PinBy;
+
+    let values = [12, 3_400, -56_000, 780_000, 9_100_000];
+    let column = |formatter: Formatter<'_, _>| values.map(|value| formatter.format(value).to_string());
+
+    assert_eq!(
+        column(Formatter::SI.pin(values, PinBy::Max)),
+        ["0.00 M", "0.00 M", "-0.05 M", "0.78 M", "9.10 M"]
+    );
+    assert_eq!(
+        column(Formatter::SI.pin(values, PinBy::Median)),
+        ["0.01 K", "3.40 K", "-56.00 K", "780.00 K", "9100.00 K"]
+    );
+    assert_eq!(
+        column(Formatter::SI.pin(values, PinBy::Percentile(0))),
+        ["12", "3400", "-56000", "780000", "9100000"]
+    );
+    assert_eq!(
+        column(Formatter::SI.pin(values, PinBy::Percentile(80))),
+        ["0.01 K", "3.40 K", "-56.00 K", "780.00 K", "9100.00 K"]
+    );
+
+    // Missing and non-finite values are ignored.
+    let formatter = Formatter::SI.pin([Some(1_500.0), None, Some(f64::NAN)], PinBy::Max);
+    assert_eq!(formatter.format(2_000_000).to_string(), "2000.00 K");
+
+    let formatter = Formatter::SI.pin(core::iter::empty::<u64>(), PinBy::Max);
+    assert_eq!(formatter.format(2_000_000).to_string(), "2000000");
+
+    let formatter = Formatter::SI.pin_unit("G").unwrap();
+    assert_eq!(formatter.format(1_500_000_000).to_string(), "1.50 G");
+    assert!(Formatter::SI.pin_unit(11).is_none());
+}
+
+#[test]
+fn test_min_max_unit() {
+    use humat::format::{Markers, Overflow};
+
+    let formatter = Formatter::BINARY
+        .with_custom_unit("B")
+        .with_min_unit("Ki")
+        .unwrap()
+        .with_max_unit("Ti")
+        .unwrap();
+
+    test_formatter! {
+        formatter;
+        0 => "0.00 KiB",
+        512 => "0.50 KiB",
+        512.0 => "0.50 KiB",
+        -512 => "-0.50 KiB",
+        1_048_576 => "1.00 MiB",
+        1_125_899_906_842_624_u64 => "1024.00 TiB",
+        1_125_899_906_842_624.0 => "1024.00 TiB"
+    }
+
+    test_formatter! {
+        formatter.with_markers(Markers::DEFAULT);
+        3 => "<0.01 KiB",
+        1_234 => "~1.20 KiB"
+    }
+
+    // The overflow policy applies only without a maximum unit.
+    test_formatter! {
+        Formatter::SI.with_max_unit(2).unwrap().with_overflow(Overflow::Scientific);
+        1_500_000_000 => "1500.00 M"
+    }
+    test_formatter! {
+        Formatter::SI.with_min_unit(1).unwrap();
+        1_500_000_000 => "1.50 G",
+        1e40 => "1e40"
+    }
+
+    test_formatter! {
+        Formatter::SI.with_max_unit("K").unwrap();
+        u128::MAX => "340282366920938500000000000000000000.00 K",
+        1e40 => "10000000000000001000000000000000000000.00 K"
+    }
+
+    // The maximum wins over the minimum.
+    test_formatter! {
+        Formatter::SI.with_min_unit("G").unwrap().with_max_unit("K").unwrap();
+        1 => "0.00 K",
+        1_500_000_000 => "1500000.00 K"
+    }
+
+    assert!(Formatter::SI.with_min_unit("Ki").is_none());
+    assert!(Formatter::SI.with_max_unit(11).is_none());
+}
+
+#[test]
+fn test_promotion() {
+    use humat::format::Markers;
+
+    let formatter = Formatter::SI.with_promotion(0.95);
+
+    test_formatter! {
+        formatter;
+        949 => "949",
+        950 => "0.95 K",
+        980 => "0.98 K",
+        -980 => "-0.98 K",
+        949_999 => "949.99 K",
+        980_000 => "0.98 M",
+        980_000.0 => "0.98 M",
+        -980_000.0 => "-0.98 M",
+        1_500_000 => "1.50 M"
+    }
+
+    let formatter = Formatter::SI.with_promotion(1.05);
+
+    test_formatter! {
+        formatter;
+        1_020 => "1020",
+        1_020.0 => "1020.00",
+        1_020_000 => "1020.00 K",
+        1_020_000.0 => "1020.00 K",
+        1_050_000 => "1.05 M"
+    }
+
+    // There is no unit to promote into beyond the last one.
+    let formatter = Formatter::GERMAN.with_promotion(0.95);
+
+    test_formatter! {
+        formatter;
+        960_000_000_000_000_u64 => "960,00 Bio",
+        999_999_999_999_999_u64 => "999,99 Bio"
+    }
+
+    assert_eq!(
+        formatter.try_format(960_000_000_000_000_u64).unwrap().to_string(),
+        "960,00 Bio"
+    );
+
+    let formatter = Formatter::SI.with_promotion(0.95).with_markers(Markers::DEFAULT);
+
+    test_formatter! {
+        formatter;
+        9.6e32 => "960.00 Q",
+        960 * 10_u128.pow(30) => "960.00 Q"
+    }
+
+    let formatter = Formatter::SI.with_promotion(1.05).with_markers(Markers::DEFAULT);
+
+    test_formatter! {
+        formatter;
+        1.02e33 => ">999.99 Q",
+        1_020 * 10_u128.pow(30) => ">999.99 Q"
+    }
+
+    // Integers and `f64` select the same unit.
+    for value in [949_000_u64, 950_000, 999_999, 1_049_999, 1_050_000] {
+        for promotion in [0.95, 1.0, 1.05] {
+            let formatter = Formatter::SI.with_promotion(promotion);
+
+            #[allow(clippy::cast_precision_loss)]
+            let double = formatter.format(value as f64).to_string();
+            assert_eq!(formatter.format(value).to_string(), double);
+        }
+    }
+}
+
+#[test]
+fn test_unit_decimal_places() {
+    use humat::format::{Markers, Overflow};
+
+    let formatter = Formatter::BINARY
+        .with_separator("")
+        .with_custom_unit("B")
+        .with_unit_decimal_places(&[0, 1, 1]);
+
+    test_formatter! {
+        formatter;
+        0 => "0B",
+        1_023 => "1023B",
+        1_023.9 => "1023B",
+        1_024 => "1.0KiB",
+        -1_536 => "-1.5KiB",
+        1_048_575 => "1023.9KiB",
+        1_572_864.0 => "1.5MiB",
+        1_610_612_736_u64 => "1.50GiB"
+    };
+
+    // Markers follow the decimal places of the unit.
+    let markers = formatter.with_markers(Markers::DEFAULT);
+
+    test_formatter! {
+        markers;
+        1_536 => "1.5KiB",
+        1_600 => "~1.5KiB",
+        0.5 => "<1B",
+        1_610_612_736_u64 => "1.50GiB"
+    };
+
+    // The last unit keeps its decimal places beyond its range.
+    let formatter = Formatter::SI
+        .with_unit_decimal_places(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3])
+        .with_overflow(Overflow::Extend);
+
+    assert_eq!(formatter.format(2 * 10_u128.pow(33)).to_string(), "2000.000 Q");
+    assert_eq!(
+        formatter
+            .format(2 * 10_u128.pow(33))
+            .with_decimal_places::<1>()
+            .to_string(),
+        "2000.0 Q"
+    );
+    assert_eq!(formatter.format(2e15).with_decimal_places::<1>().to_string(), "2.0 P");
+}